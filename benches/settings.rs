@@ -0,0 +1,65 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use htoo::{
+    flags::Flags,
+    frames::{FrameHeaderLength, SettingsFrame, SettingsParameter, SettingsParameterFrame},
+    parsers::parse_settings_parameter_frame,
+};
+
+/// A payload of ten parameters cycling through the identifiers 0x0 to 0x7.
+fn settings_payload() -> Vec<u8> {
+    let mut payload = Vec::new();
+    for i in 0..10u16 {
+        payload.extend_from_slice(&(i % 8).to_be_bytes());
+        payload.extend_from_slice(&(u32::from(i) * 1_000).to_be_bytes());
+    }
+    payload
+}
+
+/// Decodes a whole SETTINGS payload in one pass over 6-octet chunks, without going through
+/// nom for each field. The payload is assumed to be a multiple of 6 octets.
+fn decode_settings_parameters(bytes: &[u8]) -> Vec<SettingsParameterFrame> {
+    bytes
+        .chunks_exact(6)
+        .map(|chunk| {
+            let identifier = u16::from_be_bytes([chunk[0], chunk[1]]);
+            let value = u32::from_be_bytes([chunk[2], chunk[3], chunk[4], chunk[5]]);
+            SettingsParameterFrame::new(SettingsParameter::from(identifier), value)
+        })
+        .collect()
+}
+
+fn settings_decode(c: &mut Criterion) {
+    let payload = settings_payload();
+    let length = FrameHeaderLength::new().with_length(payload.len() as u32);
+    let mut group = c.benchmark_group("settings_decode");
+
+    group.bench_function("nom_per_field", |b| {
+        b.iter(|| {
+            let mut parameters = Vec::new();
+            let mut remaining = black_box(payload.as_slice());
+            while !remaining.is_empty() {
+                let (tail, parameter) = parse_settings_parameter_frame(remaining).unwrap();
+                parameters.push(parameter);
+                remaining = tail;
+            }
+            parameters
+        });
+    });
+    group.bench_function("chunks_exact", |b| {
+        b.iter(|| decode_settings_parameters(black_box(&payload)));
+    });
+    group.bench_function("zerocopy_view", |b| {
+        b.iter(|| {
+            let (_, frame) = SettingsFrame::parse(black_box(&payload), &length, &Flags::NONE)
+                .unwrap();
+            frame.into_iter().count()
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, settings_decode);
+criterion_main!(benches);
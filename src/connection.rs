@@ -0,0 +1,1392 @@
+use alloc::collections::{BTreeMap, VecDeque};
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
+
+use crate::{
+    error::Http2ParseError,
+    flags::Flags,
+    frames::{
+        ErrorCode, Frame, FrameHeader, FrameType, GoAwayFrame, SettingsFrame, WindowUpdateFrame,
+    },
+    owned::{OwnedFrame, OwnedSettingsFrame},
+    settings::Http2Settings,
+};
+
+/// The connection preface every client sends before its first frame (RFC 7540 §3.5).
+pub const CONNECTION_PREFACE: &[u8; 24] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+
+/// The initial flow-control window size before any SETTINGS are applied (RFC 7540 §6.9.2).
+pub const DEFAULT_INITIAL_WINDOW_SIZE: u32 = 65_535;
+
+/// The largest value a flow-control window may reach (RFC 7540 §6.9.1).
+pub const MAX_WINDOW_SIZE: u32 = 0x7FFF_FFFF;
+
+/// How many closed streams keep their state, so that frames still in flight on them get the
+/// precise error. Older closed streams are dropped and treated like streams closed implicitly
+/// without ever being opened, bounding memory to the active streams plus this many.
+const CLOSED_STREAMS_RETAINED: usize = 64;
+
+/// The lifecycle states of an HTTP/2 stream (RFC 7540 §5.1).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StreamState {
+    #[default]
+    Idle,
+    ReservedLocal,
+    ReservedRemote,
+    Open,
+    HalfClosedLocal,
+    HalfClosedRemote,
+    Closed,
+}
+
+impl StreamState {
+    /// Whether a frame of `frame_type` may be received on a stream in this state (RFC 7540
+    /// §5.1). Frame types that carry no stream state, such as SETTINGS or extension frames,
+    /// are always allowed.
+    ///
+    /// Closed streams still accept PRIORITY, and the RST_STREAM and WINDOW_UPDATE frames that
+    /// may be in flight when the stream closes.
+    pub fn allows(&self, frame_type: &FrameType) -> bool {
+        use FrameType::{
+            CONTINUATION, DATA, HEADERS, PRIORITY, PUSH_PROMISE, RST_STREAM, WINDOW_UPDATE,
+        };
+
+        if !matches!(
+            frame_type,
+            DATA | HEADERS | PRIORITY | RST_STREAM | PUSH_PROMISE | WINDOW_UPDATE | CONTINUATION
+        ) {
+            return true;
+        }
+        match self {
+            Self::Open | Self::HalfClosedLocal => true,
+            Self::Idle => matches!(frame_type, HEADERS | PRIORITY),
+            Self::ReservedRemote => {
+                matches!(frame_type, HEADERS | CONTINUATION | RST_STREAM | PRIORITY)
+            }
+            Self::ReservedLocal | Self::HalfClosedRemote | Self::Closed => {
+                matches!(frame_type, PRIORITY | RST_STREAM | WINDOW_UPDATE)
+            }
+        }
+    }
+
+    /// Whether the stream counts towards the concurrency limit: open or half-closed in either
+    /// direction (§5.1.2).
+    fn is_active(&self) -> bool {
+        matches!(self, Self::Open | Self::HalfClosedLocal | Self::HalfClosedRemote)
+    }
+
+    /// The error for receiving a frame this state does not allow: a connection
+    /// `PROTOCOL_ERROR` before the stream is open, and `STREAM_CLOSED` once the peer has
+    /// finished sending on it.
+    fn receive_error(&self, stream_id: u32) -> Http2ParseError {
+        match self {
+            Self::Idle | Self::ReservedLocal | Self::ReservedRemote => {
+                Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)
+            }
+            _ => Http2ParseError::Stream {
+                stream_identifier: stream_id,
+                error_code: ErrorCode::STREAM_CLOSED,
+            },
+        }
+    }
+}
+
+/// Whether a HEADERS frame opens a message or carries its trailers (RFC 7540 §8.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderKind {
+    /// Request or response headers, received before any DATA on the stream.
+    Initial,
+    /// Trailing headers, received after DATA; these must carry END_STREAM.
+    Trailers,
+}
+
+/// A stream-level event produced by [`Connection::on_frame`] for an accepted frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamEvent {
+    Headers {
+        stream_id: u32,
+        kind: HeaderKind,
+        end_stream: bool,
+    },
+    Data {
+        stream_id: u32,
+        end_stream: bool,
+    },
+    Reset {
+        stream_id: u32,
+        error_code: ErrorCode,
+    },
+}
+
+/// WINDOW_UPDATE increments worth sending after consuming flow-controlled bytes.
+///
+/// An increment is suggested once half of the initial window has been consumed, and restores
+/// the window to its initial size.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WindowUpdateSuggestion {
+    pub connection: Option<u32>,
+    pub stream: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Stream {
+    state: StreamState,
+    recv_window: i64,
+    send_window: i64,
+    /// Whether a HEADERS frame has opened the stream, as opposed to it being implicitly closed.
+    opened: bool,
+    data_seen: bool,
+    connect_tunnel: bool,
+}
+
+/// Connection-level state used to validate a sequence of received frames.
+#[derive(Debug)]
+pub struct Connection {
+    streams: BTreeMap<u32, Stream>,
+    initial_window_size: u32,
+    recv_window: i64,
+    send_window: i64,
+    peer_settings: Http2Settings,
+    /// The highest stream id opened by each initiator, indexed by `stream_id % 2`.
+    highest_opened: [u32; 2],
+    /// The last-stream-id of the latest GOAWAY received from the peer, never increasing.
+    goaway_last_stream_id: Option<u32>,
+    /// The `SETTINGS_MAX_CONCURRENT_STREAMS` this endpoint advertised to the peer.
+    max_concurrent_streams: Option<u32>,
+    /// The number of tracked streams in an active state.
+    active_streams: usize,
+    /// The most recently closed streams still in `streams`, oldest first.
+    closed_streams: VecDeque<u32>,
+    /// When our last unacknowledged SETTINGS frame was sent.
+    #[cfg(feature = "std")]
+    settings_sent_at: Option<Instant>,
+}
+
+impl Default for Connection {
+    fn default() -> Self {
+        Self {
+            streams: BTreeMap::new(),
+            initial_window_size: DEFAULT_INITIAL_WINDOW_SIZE,
+            recv_window: i64::from(DEFAULT_INITIAL_WINDOW_SIZE),
+            send_window: i64::from(DEFAULT_INITIAL_WINDOW_SIZE),
+            peer_settings: Http2Settings::default(),
+            highest_opened: [0; 2],
+            goaway_last_stream_id: None,
+            max_concurrent_streams: None,
+            active_streams: 0,
+            closed_streams: VecDeque::new(),
+            #[cfg(feature = "std")]
+            settings_sent_at: None,
+        }
+    }
+}
+
+fn suggested_increment(window: i64, target: u32) -> Option<u32> {
+    let target = i64::from(target);
+    if window <= target / 2 {
+        u32::try_from(target - window).ok()
+    } else {
+        None
+    }
+}
+
+impl Connection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current state of a stream; streams never seen are `Idle`, unless a higher
+    /// stream from the same initiator has since been opened (§5.1.1). A stream opened long
+    /// enough ago that its state was dropped is `Closed`.
+    pub fn stream_state(&self, stream_id: u32) -> StreamState {
+        let highest = self.highest_opened[(stream_id % 2) as usize];
+        match self.streams.get(&stream_id) {
+            Some(stream) if stream.state == StreamState::Idle && stream_id < highest => {
+                StreamState::Closed
+            }
+            Some(stream) => stream.state,
+            None if stream_id != 0 && stream_id <= highest => StreamState::Closed,
+            None => StreamState::Idle,
+        }
+    }
+
+    /// Whether `stream_id` is closed and its state has been dropped.
+    fn is_forgotten(&self, stream_id: u32) -> bool {
+        !self.streams.contains_key(&stream_id)
+            && self.stream_state(stream_id) == StreamState::Closed
+    }
+
+    /// Records that `stream_id` has been used, so that it and every lower idle stream of its
+    /// initiator are closed unless tracked otherwise (§5.1.1).
+    fn use_stream_id(&mut self, stream_id: u32) {
+        let highest = &mut self.highest_opened[(stream_id % 2) as usize];
+        *highest = (*highest).max(stream_id);
+    }
+
+    /// Records that the idle stream `stream_id` was opened, which implicitly closes every lower
+    /// idle stream of its initiator.
+    fn open_stream(&mut self, stream_id: u32) -> &mut Stream {
+        self.use_stream_id(stream_id);
+        let stream = self.new_stream(StreamState::Idle);
+        self.streams.entry(stream_id).or_insert(stream)
+    }
+
+    fn set_stream_state(&mut self, stream_id: u32, state: StreamState) {
+        self.stream_mut(stream_id).state = state;
+    }
+
+    fn new_stream(&self, state: StreamState) -> Stream {
+        Stream {
+            state,
+            recv_window: i64::from(self.initial_window_size),
+            send_window: i64::from(self.peer_settings.initial_window_size.get()),
+            opened: false,
+            data_seen: false,
+            connect_tunnel: false,
+        }
+    }
+
+    fn stream_mut(&mut self, stream_id: u32) -> &mut Stream {
+        let state = self.stream_state(stream_id);
+        let stream = self.new_stream(state);
+        let stream = self.streams.entry(stream_id).or_insert(stream);
+        stream.state = state;
+        stream
+    }
+
+    /// Updates the active stream count after a frame moved `stream_id` out of `before`, and
+    /// drops the oldest closed streams beyond [`CLOSED_STREAMS_RETAINED`].
+    fn settle_stream(&mut self, stream_id: u32, before: StreamState) {
+        let after = self.stream_state(stream_id);
+        match (before.is_active(), after.is_active()) {
+            (false, true) => self.active_streams += 1,
+            (true, false) => self.active_streams -= 1,
+            _ => {}
+        }
+        if after == StreamState::Closed
+            && before != StreamState::Closed
+            && self.streams.contains_key(&stream_id)
+        {
+            self.closed_streams.push_back(stream_id);
+            if self.closed_streams.len() > CLOSED_STREAMS_RETAINED {
+                let oldest = self.closed_streams.pop_front().expect("retained more than zero");
+                self.streams.remove(&oldest);
+            }
+        }
+    }
+
+    /// Marks a stream as a CONNECT tunnel (RFC 7540 §8.3, RFC 8441).
+    ///
+    /// HPACK decoding is out of scope here, so the caller marks the stream after decoding a
+    /// `:method CONNECT` request from the [`StreamEvent::Headers`] that opened it. DATA on a
+    /// tunnel carries raw tunnel bytes, and DATA still in flight after the stream closes is
+    /// accepted rather than treated as `STREAM_CLOSED`.
+    pub fn mark_connect_tunnel(&mut self, stream_id: u32) {
+        self.stream_mut(stream_id).connect_tunnel = true;
+    }
+
+    pub fn is_connect_tunnel(&self, stream_id: u32) -> bool {
+        self.streams
+            .get(&stream_id)
+            .is_some_and(|stream| stream.connect_tunnel)
+    }
+
+    /// Sets the `SETTINGS_MAX_CONCURRENT_STREAMS` this endpoint advertised; `None` means no
+    /// limit. HEADERS that would open a stream beyond it are refused with `REFUSED_STREAM`;
+    /// the stream is not tracked, but its id is used up and reads as `Closed` afterwards.
+    pub fn set_max_concurrent_streams(&mut self, max_concurrent_streams: Option<u32>) {
+        self.max_concurrent_streams = max_concurrent_streams;
+    }
+
+    /// The number of streams that count towards the concurrency limit: those open or
+    /// half-closed in either direction (§5.1.2).
+    pub fn active_streams(&self) -> usize {
+        self.active_streams
+    }
+
+    /// Returns the receive window of a stream, or of the connection when `stream_id` is 0.
+    pub fn recv_window(&self, stream_id: u32) -> i64 {
+        if stream_id == 0 {
+            return self.recv_window;
+        }
+        self.streams
+            .get(&stream_id)
+            .map_or(i64::from(self.initial_window_size), |stream| stream.recv_window)
+    }
+
+    /// Charges a received DATA frame against the flow-control windows; other frames are not
+    /// flow controlled and charge nothing.
+    ///
+    /// The entire declared length counts, padding included (§6.9.1), so a frame whose length
+    /// exceeds what is left of either window is a `FLOW_CONTROL_ERROR` even if its data alone
+    /// would fit.
+    pub fn consume_frame(
+        &mut self,
+        frame: &Frame,
+    ) -> Result<WindowUpdateSuggestion, Http2ParseError> {
+        match frame {
+            Frame::Data(header, _) => self.consume(
+                header.stream_identifier.stream_identifier(),
+                header.length.length(),
+            ),
+            _ => Ok(WindowUpdateSuggestion::default()),
+        }
+    }
+
+    /// Returns the send window of a stream, or of the connection when `stream_id` is 0.
+    pub fn send_window(&self, stream_id: u32) -> i64 {
+        if stream_id == 0 {
+            return self.send_window;
+        }
+        self.streams.get(&stream_id).map_or(
+            i64::from(self.peer_settings.initial_window_size.get()),
+            |stream| stream.send_window,
+        )
+    }
+
+    /// Credits a received WINDOW_UPDATE to the connection send window when `stream_id` is 0,
+    /// and otherwise to the stream's send window (§6.9).
+    fn credit_send_window(
+        &mut self,
+        stream_id: u32,
+        increment: u32,
+    ) -> Result<(), Http2ParseError> {
+        let (window, error) = if stream_id == 0 {
+            (
+                &mut self.send_window,
+                Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR),
+            )
+        } else {
+            (
+                &mut self.stream_mut(stream_id).send_window,
+                Http2ParseError::Stream {
+                    stream_identifier: stream_id,
+                    error_code: ErrorCode::FLOW_CONTROL_ERROR,
+                },
+            )
+        };
+        let updated = *window + i64::from(increment);
+        if updated > i64::from(MAX_WINDOW_SIZE) {
+            return Err(error);
+        }
+        *window = updated;
+        Ok(())
+    }
+
+    /// Charges `bytes` of received flow-controlled payload against the connection window and,
+    /// for a nonzero `stream_id`, the stream window.
+    ///
+    /// Returns the WINDOW_UPDATE increments worth sending, or `FLOW_CONTROL_ERROR` if the peer
+    /// exceeded a window.
+    pub fn consume(
+        &mut self,
+        stream_id: u32,
+        bytes: u32,
+    ) -> Result<WindowUpdateSuggestion, Http2ParseError> {
+        let bytes = i64::from(bytes);
+        if self.recv_window < bytes {
+            return Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR));
+        }
+        if stream_id != 0 && self.recv_window(stream_id) < bytes {
+            return Err(Http2ParseError::Stream {
+                stream_identifier: stream_id,
+                error_code: ErrorCode::FLOW_CONTROL_ERROR,
+            });
+        }
+
+        self.recv_window -= bytes;
+        let mut suggestion = WindowUpdateSuggestion {
+            connection: suggested_increment(self.recv_window, DEFAULT_INITIAL_WINDOW_SIZE),
+            stream: None,
+        };
+        if stream_id != 0 && !self.is_forgotten(stream_id) {
+            let initial_window_size = self.initial_window_size;
+            let stream = self.stream_mut(stream_id);
+            stream.recv_window -= bytes;
+            suggestion.stream = suggested_increment(stream.recv_window, initial_window_size);
+        }
+
+        Ok(suggestion)
+    }
+
+    /// The last stream id of the GOAWAY received from the peer, if any; a peer may send
+    /// several, each lowering the limit (§6.8). A graceful shutdown typically starts at
+    /// 2^31-1 and follows up with the real last stream id.
+    pub fn goaway_last_stream_id(&self) -> Option<u32> {
+        self.goaway_last_stream_id
+    }
+
+    /// Whether the peer has sent a GOAWAY, so the connection is draining: streams at or below
+    /// the last stream id finish, and no others are started.
+    pub fn is_shutting_down(&self) -> bool {
+        self.goaway_last_stream_id.is_some()
+    }
+
+    /// Whether `stream_id` may still be opened: it is idle and, once a GOAWAY has been
+    /// received, no higher than its last stream id.
+    pub fn can_open_stream(&self, stream_id: u32) -> bool {
+        stream_id != 0
+            && self.stream_state(stream_id) == StreamState::Idle
+            && self.goaway_last_stream_id.is_none_or(|last| stream_id <= last)
+    }
+
+    /// The peer's settings as of the last SETTINGS frame received from it.
+    pub fn peer_settings(&self) -> &Http2Settings {
+        &self.peer_settings
+    }
+
+    /// Applies the parameters of a received non-ACK SETTINGS frame in order and returns the
+    /// SETTINGS ACK to send.
+    ///
+    /// Every parameter is validated before any takes effect, so an invalid frame leaves the
+    /// peer settings untouched. A parameter repeated within the frame keeps its last value.
+    ///
+    /// A new `SETTINGS_INITIAL_WINDOW_SIZE` shifts the send window of every stream by the
+    /// difference from the old value, possibly below zero; pushing any window above 2^31-1
+    /// is a connection `FLOW_CONTROL_ERROR` (§6.9.2).
+    pub fn receive_settings(
+        &mut self,
+        frame: &SettingsFrame,
+    ) -> Result<OwnedFrame, Http2ParseError> {
+        let mut settings = self.peer_settings;
+        for (parameter, value) in frame {
+            settings.apply(parameter, value)?;
+        }
+
+        let delta = i64::from(settings.initial_window_size.get())
+            - i64::from(self.peer_settings.initial_window_size.get());
+        if self
+            .streams
+            .values()
+            .any(|stream| stream.send_window + delta > i64::from(MAX_WINDOW_SIZE))
+        {
+            return Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR));
+        }
+        for stream in self.streams.values_mut() {
+            stream.send_window += delta;
+        }
+        self.peer_settings = settings;
+
+        OwnedSettingsFrame { parameters: None }.into_ack_frame()
+    }
+
+    /// Records that a SETTINGS frame was sent at `at` and now awaits the peer's ACK.
+    ///
+    /// Only the first unacknowledged frame is timed, since the peer acknowledges in order.
+    #[cfg(feature = "std")]
+    pub fn mark_settings_sent(&mut self, at: Instant) {
+        self.settings_sent_at.get_or_insert(at);
+    }
+
+    /// Returns `SETTINGS_TIMEOUT` when sent SETTINGS have gone unacknowledged for longer than
+    /// `timeout` (RFC 7540 §6.5.3).
+    #[cfg(feature = "std")]
+    pub fn check_settings_timeout(&self, now: Instant, timeout: Duration) -> Option<ErrorCode> {
+        self.settings_sent_at
+            .filter(|sent_at| now.saturating_duration_since(*sent_at) > timeout)
+            .map(|_| ErrorCode::SETTINGS_TIMEOUT)
+    }
+
+    fn on_settings_ack(&mut self) {
+        #[cfg(feature = "std")]
+        {
+            self.settings_sent_at = None;
+        }
+    }
+
+    /// Builds a WINDOW_UPDATE for the stream (or the connection when `stream_id` is 0) and
+    /// credits the increment to the matching receive window.
+    pub fn window_update_frame(
+        &mut self,
+        stream_id: u32,
+        increment: u32,
+    ) -> Result<OwnedFrame, Http2ParseError> {
+        let frame = WindowUpdateFrame::new(increment)
+            .ok_or(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR))?;
+        let header = FrameHeader::new(FrameType::WINDOW_UPDATE, Flags::NONE, stream_id, 4)
+            .ok_or(Http2ParseError::FrameSize)?;
+
+        let (window, error) = if stream_id == 0 {
+            (
+                &mut self.recv_window,
+                Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR),
+            )
+        } else if self.is_forgotten(stream_id) {
+            return Ok(OwnedFrame::WindowUpdate(header, frame));
+        } else {
+            (
+                &mut self.stream_mut(stream_id).recv_window,
+                Http2ParseError::Stream {
+                    stream_identifier: stream_id,
+                    error_code: ErrorCode::FLOW_CONTROL_ERROR,
+                },
+            )
+        };
+        let updated = *window + i64::from(increment);
+        if updated > i64::from(MAX_WINDOW_SIZE) {
+            return Err(error);
+        }
+        *window = updated;
+
+        Ok(OwnedFrame::WindowUpdate(header, frame))
+    }
+
+    /// Builds the GOAWAY to send for a connection error with `code`, naming the highest
+    /// stream the peer opened so far as the last one processed (§6.8). `is_client` is this
+    /// endpoint's role, so a client names the highest even stream and a server the highest
+    /// odd one. Debug data too long for one frame fails with [`Http2ParseError::FrameSize`].
+    pub fn goaway_for(
+        &self,
+        is_client: bool,
+        code: ErrorCode,
+        debug: Option<&[u8]>,
+    ) -> Result<OwnedFrame, Http2ParseError> {
+        let peer_parity = if is_client { 0 } else { 1 };
+        let last_stream_id = self.highest_opened[peer_parity];
+        let frame = GoAwayFrame::new(last_stream_id, code, debug);
+        Ok(Frame::GoAway(frame.frame_header()?, frame).to_owned())
+    }
+
+    /// Validates a received frame against the connection state and applies its effects.
+    ///
+    /// Returns the stream-level event for HEADERS, DATA, and RST_STREAM frames. DATA is only
+    /// accepted on open or half-closed (local) streams, except on CONNECT tunnels. A stream
+    /// reserved by PUSH_PROMISE accepts no DATA until HEADERS arrive on it, and DATA before
+    /// any HEADERS on a new stream is a connection `PROTOCOL_ERROR`. Once a GOAWAY has been
+    /// received, HEADERS opening a stream above its last stream id are refused with
+    /// `REFUSED_STREAM`, and a later GOAWAY raising that id is a connection `PROTOCOL_ERROR`.
+    ///
+    /// A frame on a stream id its type does not allow is a connection `PROTOCOL_ERROR`; for
+    /// any connection error, [`Connection::goaway_for`] builds the GOAWAY to answer with.
+    pub fn on_frame(&mut self, frame: &Frame) -> Result<Option<StreamEvent>, Http2ParseError> {
+        let header = frame.header();
+        header.validate_stream_identifier()?;
+        let stream_id = header.stream_identifier.stream_identifier();
+        let before = self.stream_state(stream_id);
+
+        let stream_dependency = match frame {
+            Frame::Priority(_, priority) => Some(&priority.stream_dependency),
+            Frame::Headers(_, headers) => headers.stream_dependency.as_ref(),
+            _ => None,
+        };
+        if stream_dependency.is_some_and(|dep| dep.stream_identifier() == stream_id) {
+            return Err(Http2ParseError::Stream {
+                stream_identifier: stream_id,
+                error_code: ErrorCode::PROTOCOL_ERROR,
+            });
+        }
+
+        let end_stream = header.flags.contains(Flags::END_STREAM);
+        let event = match frame {
+            Frame::Headers(..) => {
+                let opened = self
+                    .streams
+                    .get(&stream_id)
+                    .is_some_and(|stream| stream.opened);
+                match self.stream_state(stream_id) {
+                    // No new stream above a GOAWAY's last stream id will be processed (§6.8),
+                    // nor one beyond the advertised concurrency limit (§5.1.2). The refused id
+                    // may not be reused (§5.1.1).
+                    StreamState::Idle
+                        if self.goaway_last_stream_id.is_some_and(|last| stream_id > last)
+                            || self.max_concurrent_streams.is_some_and(|max| {
+                                self.active_streams() >= max as usize
+                            }) =>
+                    {
+                        self.use_stream_id(stream_id);
+                        return Err(Http2ParseError::Stream {
+                            stream_identifier: stream_id,
+                            error_code: ErrorCode::REFUSED_STREAM,
+                        });
+                    }
+                    StreamState::Idle => {
+                        self.open_stream(stream_id);
+                    }
+                    // Stream ids must increase, so a skipped id can never be opened (§5.1.1).
+                    StreamState::Closed if !opened => {
+                        return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+                    }
+                    state if !state.allows(&FrameType::HEADERS) => {
+                        return Err(state.receive_error(stream_id));
+                    }
+                    _ => {}
+                }
+                let stream = self.stream_mut(stream_id);
+                stream.opened = true;
+                let kind = if stream.data_seen {
+                    HeaderKind::Trailers
+                } else {
+                    HeaderKind::Initial
+                };
+                if kind == HeaderKind::Trailers && !end_stream {
+                    return Err(Http2ParseError::Stream {
+                        stream_identifier: stream_id,
+                        error_code: ErrorCode::PROTOCOL_ERROR,
+                    });
+                }
+                stream.state = match (stream.state, end_stream) {
+                    (StreamState::Idle, false) => StreamState::Open,
+                    (StreamState::Idle | StreamState::Open, true) => StreamState::HalfClosedRemote,
+                    (StreamState::ReservedRemote, false) => StreamState::HalfClosedLocal,
+                    (StreamState::ReservedRemote | StreamState::HalfClosedLocal, true) => {
+                        StreamState::Closed
+                    }
+                    (state, _) => state,
+                };
+                Some(StreamEvent::Headers {
+                    stream_id,
+                    kind,
+                    end_stream,
+                })
+            }
+            Frame::Data(..) => {
+                match self.stream_state(stream_id) {
+                    StreamState::HalfClosedRemote | StreamState::Closed
+                        if self.is_connect_tunnel(stream_id) => {}
+                    state if !state.allows(&FrameType::DATA) => {
+                        return Err(state.receive_error(stream_id));
+                    }
+                    _ => {}
+                }
+                let stream = self.stream_mut(stream_id);
+                stream.data_seen = true;
+                stream.state = match (stream.state, end_stream) {
+                    (StreamState::Open, true) => StreamState::HalfClosedRemote,
+                    (StreamState::HalfClosedLocal, true) => StreamState::Closed,
+                    (state, _) => state,
+                };
+                Some(StreamEvent::Data {
+                    stream_id,
+                    end_stream,
+                })
+            }
+            Frame::RstStream(_, rst_stream) => {
+                let state = self.stream_state(stream_id);
+                if stream_id == 0 || !state.allows(&FrameType::RST_STREAM) {
+                    return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+                }
+                // A closed stream stays as it is, so that a reset of one whose state was
+                // dropped does not track it again.
+                if state != StreamState::Closed {
+                    self.set_stream_state(stream_id, StreamState::Closed);
+                }
+                Some(StreamEvent::Reset {
+                    stream_id,
+                    error_code: rst_stream.error_code,
+                })
+            }
+            // A promise is only valid on a stream the peer can still send on, and reserves an
+            // idle stream id (§5.1, §6.6).
+            Frame::PushPromise(_, push_promise) => {
+                let promised_id = push_promise.promised_stream_identifier.stream_identifier();
+                let associated_open = self.stream_state(stream_id).allows(&FrameType::PUSH_PROMISE);
+                if !associated_open || self.stream_state(promised_id) != StreamState::Idle {
+                    return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+                }
+                let promised = self.open_stream(promised_id);
+                promised.state = StreamState::ReservedRemote;
+                promised.opened = true;
+                None
+            }
+            // WINDOW_UPDATE stays valid once the peer has half-closed the stream, and is ignored
+            // on closed streams where it may still be in flight (§5.1). An increment of 0 is a
+            // PROTOCOL_ERROR on the connection or the stream it was sent on (§6.9).
+            Frame::WindowUpdate(_, window_update) => {
+                let increment = window_update.window_size_increment.value();
+                match self.stream_state(stream_id) {
+                    _ if stream_id == 0 && increment == 0 => {
+                        return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+                    }
+                    _ if stream_id == 0 => self.credit_send_window(0, increment)?,
+                    state if !state.allows(&FrameType::WINDOW_UPDATE) => {
+                        return Err(state.receive_error(stream_id));
+                    }
+                    _ if increment == 0 => {
+                        return Err(Http2ParseError::Stream {
+                            stream_identifier: stream_id,
+                            error_code: ErrorCode::PROTOCOL_ERROR,
+                        });
+                    }
+                    StreamState::Closed => {}
+                    _ => self.credit_send_window(stream_id, increment)?,
+                }
+                None
+            }
+            Frame::GoAway(_, goaway) => {
+                // Each GOAWAY may only lower the last stream id, which is what lets a graceful
+                // shutdown announce 2^31-1 before the real id (§6.8).
+                let last_stream_id = goaway.last_stream_identifier.stream_identifier();
+                if self.goaway_last_stream_id.is_some_and(|last| last_stream_id > last) {
+                    return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+                }
+                self.goaway_last_stream_id = Some(last_stream_id);
+                None
+            }
+            Frame::Settings(..) if header.flags.contains(Flags::ACK) => {
+                self.on_settings_ack();
+                None
+            }
+            // ALTSVC is advisory and carries no stream state (RFC 7838 §4).
+            Frame::AltSvc(..) => None,
+            // ORIGIN is connection-level; parsing turns it into `Frame::Ignored` elsewhere.
+            Frame::Origin(..) | Frame::Ignored(..) => None,
+            _ => None,
+        };
+
+        if stream_id != 0 {
+            self.settle_stream(stream_id, before);
+        }
+        Ok(event)
+    }
+}
+
+#[cfg(test)]
+mod connection_tests {
+    use alloc::vec;
+
+    use crate::{
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{ErrorCode, Frame, FrameType, SettingsParameter},
+        owned::OwnedFrame,
+        test_corpus::{
+            data_frame_bytes, frame_bytes, goaway_frame_bytes, headers_frame_bytes,
+            priority_frame_bytes, push_promise_frame_bytes, rst_stream_frame_bytes,
+            settings_frame_bytes, window_update_frame_bytes,
+        },
+    };
+
+    use super::{Connection, HeaderKind, StreamEvent, StreamState, WindowUpdateSuggestion};
+
+    fn receive(
+        connection: &mut Connection,
+        bytes: &[u8],
+    ) -> Result<Option<StreamEvent>, Http2ParseError> {
+        let (_, frame) = Frame::parse(bytes)?;
+        connection.on_frame(&frame)
+    }
+
+    #[test]
+    fn test_stream_state_allows() {
+        let table = [
+            (StreamState::Idle, FrameType::HEADERS, true),
+            (StreamState::Idle, FrameType::DATA, false),
+            (StreamState::ReservedRemote, FrameType::HEADERS, true),
+            (StreamState::ReservedRemote, FrameType::DATA, false),
+            (StreamState::Open, FrameType::DATA, true),
+            (StreamState::HalfClosedLocal, FrameType::DATA, true),
+            (StreamState::HalfClosedLocal, FrameType::PUSH_PROMISE, true),
+            (StreamState::HalfClosedRemote, FrameType::DATA, false),
+            (StreamState::HalfClosedRemote, FrameType::HEADERS, false),
+            (StreamState::HalfClosedRemote, FrameType::WINDOW_UPDATE, true),
+            (StreamState::Closed, FrameType::PRIORITY, true),
+            (StreamState::Closed, FrameType::DATA, false),
+            (StreamState::Closed, FrameType::UNKNOWN(0x42), true),
+        ];
+        for (state, frame_type, allowed) in table {
+            assert_eq!(allowed, state.allows(&frame_type), "{state:?} {frame_type}");
+        }
+    }
+
+    #[test]
+    fn test_rst_stream_on_idle_stream() {
+        let mut connection = Connection::new();
+        let rst = rst_stream_frame_bytes(7, ErrorCode::CANCEL);
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &rst)
+        );
+
+        let headers = headers_frame_bytes(7, Flags::NONE, &[], None);
+        assert!(receive(&mut connection, &headers).is_ok());
+        assert!(receive(&mut connection, &rst).is_ok());
+        assert_eq!(StreamState::Closed, connection.stream_state(7));
+    }
+
+    #[test]
+    fn test_self_dependency() {
+        let mut connection = Connection::new();
+        let stream_error = Err(Http2ParseError::Stream {
+            stream_identifier: 3,
+            error_code: ErrorCode::PROTOCOL_ERROR,
+        });
+
+        let priority = priority_frame_bytes(3, 3, 16);
+        assert_eq!(stream_error, receive(&mut connection, &priority));
+
+        let headers = headers_frame_bytes(3, Flags::NONE, &[], Some((3, 16)));
+        assert_eq!(stream_error, receive(&mut connection, &headers));
+        assert_eq!(StreamState::Idle, connection.stream_state(3));
+
+        let priority = priority_frame_bytes(3, 1, 16);
+        assert_eq!(Ok(None), receive(&mut connection, &priority));
+    }
+
+    #[test]
+    fn test_consume_suggests_window_update() {
+        let mut connection = Connection::new();
+
+        assert_eq!(
+            Ok(WindowUpdateSuggestion::default()),
+            connection.consume(1, 20_000)
+        );
+        assert_eq!(
+            Ok(WindowUpdateSuggestion {
+                connection: Some(40_000),
+                stream: Some(40_000),
+            }),
+            connection.consume(1, 20_000)
+        );
+        assert_eq!(25_535, connection.recv_window(1));
+
+        let update = connection.window_update_frame(1, 40_000).unwrap();
+        match update {
+            OwnedFrame::WindowUpdate(header, frame) => {
+                assert_eq!(FrameType::WINDOW_UPDATE, header.frame_type);
+                assert_eq!(1, header.stream_identifier.stream_identifier());
+                assert_eq!(40_000, frame.window_size_increment.value());
+            }
+            _ => panic!("expected a WINDOW_UPDATE frame"),
+        }
+        assert_eq!(65_535, connection.recv_window(1));
+        assert_eq!(25_535, connection.recv_window(0));
+
+        assert!(connection.window_update_frame(1, 0).is_err());
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 1,
+                error_code: ErrorCode::FLOW_CONTROL_ERROR,
+            }),
+            connection.window_update_frame(1, 0x7FFF_FFFF)
+        );
+    }
+
+    #[test]
+    fn test_headers_data_trailers() {
+        let mut connection = Connection::new();
+
+        assert_eq!(
+            Ok(Some(StreamEvent::Headers {
+                stream_id: 1,
+                kind: HeaderKind::Initial,
+                end_stream: false,
+            })),
+            receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None))
+        );
+        assert_eq!(
+            Ok(Some(StreamEvent::Data {
+                stream_id: 1,
+                end_stream: false,
+            })),
+            receive(&mut connection, &data_frame_bytes(1, b"body", None))
+        );
+        assert_eq!(
+            Ok(Some(StreamEvent::Headers {
+                stream_id: 1,
+                kind: HeaderKind::Trailers,
+                end_stream: true,
+            })),
+            receive(&mut connection, &headers_frame_bytes(1, Flags::END_STREAM, b"\x40", None))
+        );
+        assert_eq!(StreamState::HalfClosedRemote, connection.stream_state(1));
+
+        receive(&mut connection, &headers_frame_bytes(3, Flags::NONE, b"\x82", None)).unwrap();
+        receive(&mut connection, &data_frame_bytes(3, b"body", None)).unwrap();
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 3,
+                error_code: ErrorCode::PROTOCOL_ERROR,
+            }),
+            receive(&mut connection, &headers_frame_bytes(3, Flags::NONE, b"\x40", None))
+        );
+    }
+
+    #[test]
+    fn test_receive_settings_returns_ack() {
+        let mut connection = Connection::new();
+        let bytes = settings_frame_bytes(&[
+            (SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 32_768),
+            (SettingsParameter::SETTINGS_ENABLE_PUSH, 0),
+            (SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 65_536),
+        ]);
+        let Ok((_, Frame::Settings(_, settings))) = Frame::parse(&bytes) else {
+            panic!("expected a SETTINGS frame");
+        };
+
+        let ack = connection.receive_settings(&settings).unwrap();
+        let header = ack.header();
+        assert_eq!(FrameType::SETTINGS, header.frame_type);
+        assert_eq!(Flags::ACK, header.flags);
+        assert_eq!(0, header.stream_identifier.stream_identifier());
+        assert_eq!(0, header.length.length());
+        assert_eq!(0, ack.as_ref().payload_len());
+
+        assert_eq!(65_536, connection.peer_settings().max_frame_size.get());
+        assert!(!connection.peer_settings().enable_push.get());
+
+        let bytes = settings_frame_bytes(&[
+            (SettingsParameter::SETTINGS_ENABLE_PUSH, 1),
+            (SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 1),
+        ]);
+        let Ok((_, Frame::Settings(_, settings))) = Frame::parse(&bytes) else {
+            panic!("expected a SETTINGS frame");
+        };
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            connection.receive_settings(&settings)
+        );
+        assert!(!connection.peer_settings().enable_push.get());
+    }
+
+    #[test]
+    fn test_initial_window_size_change() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None)).unwrap();
+        receive(&mut connection, &window_update_frame_bytes(1, 100)).unwrap();
+        assert_eq!(65_635, connection.send_window(1));
+
+        let receive_settings = |connection: &mut Connection, value| {
+            let bytes =
+                settings_frame_bytes(&[(SettingsParameter::SETTINGS_INITIAL_WINDOW_SIZE, value)]);
+            let Ok((_, Frame::Settings(_, settings))) = Frame::parse(&bytes) else {
+                panic!("expected a SETTINGS frame");
+            };
+            connection.receive_settings(&settings).map(|_| ())
+        };
+
+        receive_settings(&mut connection, 1_000).unwrap();
+        assert_eq!(1_100, connection.send_window(1));
+        assert_eq!(1_000, connection.send_window(3));
+        receive_settings(&mut connection, 0).unwrap();
+        assert_eq!(100, connection.send_window(1));
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR)),
+            receive_settings(&mut connection, 0x7FFF_FFFF)
+        );
+        assert_eq!(100, connection.send_window(1));
+        assert_eq!(0, connection.peer_settings().initial_window_size.get());
+    }
+
+    #[test]
+    fn test_connect_tunnel_data() {
+        let mut connection = Connection::new();
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &data_frame_bytes(1, b"early", None))
+        );
+
+        for stream_id in [1, 3] {
+            receive(&mut connection, &headers_frame_bytes(stream_id, Flags::NONE, b"\x02", None))
+                .unwrap();
+        }
+        connection.mark_connect_tunnel(1);
+        assert!(connection.is_connect_tunnel(1));
+        assert!(!connection.is_connect_tunnel(3));
+
+        for stream_id in [1, 3] {
+            let mut bytes = data_frame_bytes(stream_id, b"\x16\x03\x01", None);
+            bytes[4] = Flags::END_STREAM.bits();
+            assert!(receive(&mut connection, &bytes).is_ok());
+            assert_eq!(StreamState::HalfClosedRemote, connection.stream_state(stream_id));
+        }
+
+        assert_eq!(
+            Ok(Some(StreamEvent::Data {
+                stream_id: 1,
+                end_stream: false,
+            })),
+            receive(&mut connection, &data_frame_bytes(1, b"late", None))
+        );
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 3,
+                error_code: ErrorCode::STREAM_CLOSED,
+            }),
+            receive(&mut connection, &data_frame_bytes(3, b"late", None))
+        );
+    }
+
+    #[test]
+    fn test_opening_stream_closes_lower_idle_streams() {
+        let mut connection = Connection::new();
+        connection.mark_connect_tunnel(1);
+        assert_eq!(StreamState::Idle, connection.stream_state(1));
+
+        receive(&mut connection, &headers_frame_bytes(5, Flags::NONE, b"\x82", None)).unwrap();
+
+        assert_eq!(StreamState::Open, connection.stream_state(5));
+        assert_eq!(StreamState::Closed, connection.stream_state(1));
+        assert_eq!(StreamState::Closed, connection.stream_state(3));
+        assert_eq!(StreamState::Idle, connection.stream_state(2));
+        assert_eq!(StreamState::Idle, connection.stream_state(7));
+
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 3,
+                error_code: ErrorCode::STREAM_CLOSED,
+            }),
+            receive(&mut connection, &data_frame_bytes(3, b"late", None))
+        );
+        assert!(receive(&mut connection, &rst_stream_frame_bytes(3, ErrorCode::CANCEL)).is_ok());
+    }
+
+    #[test]
+    fn test_window_update_on_half_closed_remote() {
+        let mut connection = Connection::new();
+        let stream_closed = Err(Http2ParseError::Stream {
+            stream_identifier: 1,
+            error_code: ErrorCode::STREAM_CLOSED,
+        });
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &window_update_frame_bytes(1, 1024))
+        );
+
+        receive(&mut connection, &headers_frame_bytes(1, Flags::END_STREAM, b"\x82", None))
+            .unwrap();
+        assert_eq!(StreamState::HalfClosedRemote, connection.stream_state(1));
+
+        assert_eq!(Ok(None), receive(&mut connection, &window_update_frame_bytes(1, 1024)));
+        assert_eq!(stream_closed, receive(&mut connection, &data_frame_bytes(1, b"x", None)));
+        assert_eq!(
+            stream_closed,
+            receive(&mut connection, &headers_frame_bytes(1, Flags::END_STREAM, b"\x40", None))
+        );
+    }
+
+    #[test]
+    fn test_stream_ids_must_increase() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(3, Flags::NONE, b"\x82", None)).unwrap();
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None))
+        );
+        assert!(
+            receive(&mut connection, &headers_frame_bytes(3, Flags::END_STREAM, b"\x40", None))
+                .is_ok()
+        );
+        let server_stream = headers_frame_bytes(2, Flags::NONE, b"\x82", None);
+        assert!(receive(&mut connection, &server_stream).is_ok());
+    }
+
+    #[test]
+    fn test_push_promise_reserves_stream() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None)).unwrap();
+
+        assert_eq!(Ok(None), receive(&mut connection, &push_promise_frame_bytes(1, 2, b"\x82")));
+        assert_eq!(StreamState::ReservedRemote, connection.stream_state(2));
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &push_promise_frame_bytes(1, 2, b"\x82"))
+        );
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &data_frame_bytes(2, b"pushed", None))
+        );
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &window_update_frame_bytes(2, 1024))
+        );
+
+        assert_eq!(
+            Ok(Some(StreamEvent::Headers {
+                stream_id: 2,
+                kind: HeaderKind::Initial,
+                end_stream: false,
+            })),
+            receive(&mut connection, &headers_frame_bytes(2, Flags::NONE, b"\x88", None))
+        );
+        assert_eq!(StreamState::HalfClosedLocal, connection.stream_state(2));
+        assert!(receive(&mut connection, &data_frame_bytes(2, b"pushed", None)).is_ok());
+        assert_eq!(2, connection.active_streams());
+
+        let end = frame_bytes(FrameType::DATA, Flags::END_STREAM, 2, b"done");
+        assert!(receive(&mut connection, &end).is_ok());
+        assert_eq!(StreamState::Closed, connection.stream_state(2));
+        assert_eq!(1, connection.active_streams());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_settings_timeout() {
+        use std::time::{Duration, Instant};
+
+        use crate::test_corpus::settings_ack_bytes;
+
+        let mut connection = Connection::new();
+        let timeout = Duration::from_secs(10);
+        let sent_at = Instant::now();
+        assert_eq!(None, connection.check_settings_timeout(sent_at, timeout));
+
+        connection.mark_settings_sent(sent_at);
+        assert_eq!(None, connection.check_settings_timeout(sent_at + timeout, timeout));
+        assert_eq!(
+            Some(ErrorCode::SETTINGS_TIMEOUT),
+            connection.check_settings_timeout(sent_at + Duration::from_secs(11), timeout)
+        );
+
+        assert_eq!(Ok(None), receive(&mut connection, &settings_ack_bytes()));
+        assert_eq!(
+            None,
+            connection.check_settings_timeout(sent_at + Duration::from_secs(11), timeout)
+        );
+    }
+
+    #[test]
+    fn test_window_update_routing() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None)).unwrap();
+
+        assert_eq!(Ok(None), receive(&mut connection, &window_update_frame_bytes(0, 1_000)));
+        assert_eq!(66_535, connection.send_window(0));
+        assert_eq!(65_535, connection.send_window(1));
+
+        assert_eq!(Ok(None), receive(&mut connection, &window_update_frame_bytes(1, 2_000)));
+        assert_eq!(66_535, connection.send_window(0));
+        assert_eq!(67_535, connection.send_window(1));
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &window_update_frame_bytes(0, 0))
+        );
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 1,
+                error_code: ErrorCode::PROTOCOL_ERROR,
+            }),
+            receive(&mut connection, &window_update_frame_bytes(1, 0))
+        );
+        assert_eq!(66_535, connection.send_window(0));
+        assert_eq!(67_535, connection.send_window(1));
+    }
+
+    #[test]
+    fn test_window_update_overflow() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None)).unwrap();
+        let overflow = 0x7FFF_FFFF - 65_535 + 1;
+
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 1,
+                error_code: ErrorCode::FLOW_CONTROL_ERROR,
+            }),
+            receive(&mut connection, &window_update_frame_bytes(1, overflow))
+        );
+        assert_eq!(65_535, connection.send_window(1));
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR)),
+            receive(&mut connection, &window_update_frame_bytes(0, overflow))
+        );
+        assert_eq!(
+            Ok(None),
+            receive(&mut connection, &window_update_frame_bytes(0, overflow - 1))
+        );
+        assert_eq!(0x7FFF_FFFF, connection.send_window(0));
+    }
+
+    #[test]
+    fn test_goaway_for_connection_error() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(3, Flags::NONE, b"\x82", None)).unwrap();
+
+        let mut settings = settings_frame_bytes(&[]);
+        settings[8] = 1;
+        let Err(Http2ParseError::Connection(code)) = receive(&mut connection, &settings) else {
+            panic!("expected a connection error");
+        };
+        assert_eq!(ErrorCode::PROTOCOL_ERROR, code);
+
+        let Ok(OwnedFrame::GoAway(header, goaway)) =
+            connection.goaway_for(false, code, Some(b"settings"))
+        else {
+            panic!("expected a GOAWAY frame");
+        };
+        assert_eq!(16, header.length.length());
+        assert_eq!(0, header.stream_identifier.stream_identifier());
+        assert_eq!(3, goaway.last_stream_identifier.stream_identifier());
+        assert_eq!(ErrorCode::PROTOCOL_ERROR, goaway.error_code);
+        assert_eq!(Some(b"settings".to_vec()), goaway.debug_data);
+
+        let debug = vec![0; 0xFF_FFFF - 7];
+        assert_eq!(
+            Err(Http2ParseError::FrameSize),
+            connection.goaway_for(false, code, Some(&debug))
+        );
+    }
+
+    #[test]
+    fn test_goaway_for_names_peer_streams() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(5, Flags::NONE, b"\x88", None)).unwrap();
+        receive(&mut connection, &push_promise_frame_bytes(5, 2, b"\x82")).unwrap();
+        receive(&mut connection, &headers_frame_bytes(2, Flags::NONE, b"\x88", None)).unwrap();
+
+        let last_stream_id = |is_client| match connection.goaway_for(
+            is_client,
+            ErrorCode::NO_ERROR,
+            None,
+        ) {
+            Ok(OwnedFrame::GoAway(_, goaway)) => goaway.last_stream_identifier.stream_identifier(),
+            other => panic!("expected a GOAWAY frame, got {other:?}"),
+        };
+        assert_eq!(2, last_stream_id(true));
+        assert_eq!(5, last_stream_id(false));
+    }
+
+    #[test]
+    fn test_max_concurrent_streams() {
+        let mut connection = Connection::new();
+        connection.set_max_concurrent_streams(Some(2));
+        for stream_id in [1, 3] {
+            let headers = headers_frame_bytes(stream_id, Flags::NONE, b"\x82", None);
+            receive(&mut connection, &headers).unwrap();
+        }
+        assert_eq!(2, connection.active_streams());
+
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 5,
+                error_code: ErrorCode::REFUSED_STREAM,
+            }),
+            receive(&mut connection, &headers_frame_bytes(5, Flags::NONE, b"\x82", None))
+        );
+        assert_eq!(2, connection.active_streams());
+        assert_eq!(StreamState::Closed, connection.stream_state(5));
+
+        receive(&mut connection, &rst_stream_frame_bytes(1, ErrorCode::CANCEL)).unwrap();
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &headers_frame_bytes(5, Flags::NONE, b"\x82", None))
+        );
+        let headers = headers_frame_bytes(7, Flags::NONE, b"\x82", None);
+        assert!(receive(&mut connection, &headers).is_ok());
+    }
+
+    #[test]
+    fn test_closed_streams_are_dropped() {
+        let mut connection = Connection::new();
+        for stream_id in (1..2_000).step_by(2) {
+            let headers = headers_frame_bytes(stream_id, Flags::END_STREAM, b"\x82", None);
+            receive(&mut connection, &headers).unwrap();
+            assert_eq!(1, connection.active_streams());
+            receive(&mut connection, &rst_stream_frame_bytes(stream_id, ErrorCode::CANCEL))
+                .unwrap();
+        }
+        assert_eq!(0, connection.active_streams());
+        assert!(connection.streams.len() <= super::CLOSED_STREAMS_RETAINED);
+
+        assert_eq!(StreamState::Closed, connection.stream_state(1));
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 1,
+                error_code: ErrorCode::STREAM_CLOSED,
+            }),
+            receive(&mut connection, &data_frame_bytes(1, b"late", None))
+        );
+        receive(&mut connection, &window_update_frame_bytes(1, 1)).unwrap();
+        assert!(!connection.streams.contains_key(&1));
+    }
+
+    #[test]
+    fn test_reset_of_closed_streams_is_not_tracked() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(2_001, Flags::NONE, b"\x82", None)).unwrap();
+        for stream_id in (1..2_001).step_by(2) {
+            receive(&mut connection, &rst_stream_frame_bytes(stream_id, ErrorCode::CANCEL))
+                .unwrap();
+        }
+        assert!(connection.streams.len() <= super::CLOSED_STREAMS_RETAINED);
+        assert_eq!(StreamState::Closed, connection.stream_state(1));
+        assert_eq!(StreamState::Open, connection.stream_state(2_001));
+    }
+
+    #[test]
+    fn test_graceful_shutdown() {
+        let mut connection = Connection::new();
+        assert!(!connection.is_shutting_down());
+        assert!(connection.can_open_stream(9));
+
+        receive(&mut connection, &goaway_frame_bytes(7, ErrorCode::NO_ERROR, &[])).unwrap();
+        assert!(connection.is_shutting_down());
+        assert!(!connection.can_open_stream(9));
+        assert!(connection.can_open_stream(5));
+        assert!(!connection.can_open_stream(0));
+    }
+
+    #[test]
+    fn test_two_phase_goaway() {
+        let mut connection = Connection::new();
+        let announce = goaway_frame_bytes(0x7FFF_FFFF, ErrorCode::NO_ERROR, &[]);
+        receive(&mut connection, &announce).unwrap();
+        assert_eq!(Some(0x7FFF_FFFF), connection.goaway_last_stream_id());
+        receive(&mut connection, &goaway_frame_bytes(7, ErrorCode::NO_ERROR, &[])).unwrap();
+        assert_eq!(Some(7), connection.goaway_last_stream_id());
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &goaway_frame_bytes(9, ErrorCode::NO_ERROR, &[]))
+        );
+        assert_eq!(Some(7), connection.goaway_last_stream_id());
+    }
+
+    #[test]
+    fn test_frame_sequencing() {
+        let mut connection = Connection::new();
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            receive(&mut connection, &data_frame_bytes(1, b"early", None))
+        );
+
+        receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None)).unwrap();
+        receive(&mut connection, &goaway_frame_bytes(3, ErrorCode::NO_ERROR, &[])).unwrap();
+        assert_eq!(Some(3), connection.goaway_last_stream_id());
+
+        let below_limit = headers_frame_bytes(3, Flags::NONE, b"\x82", None);
+        assert!(receive(&mut connection, &below_limit).is_ok());
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 5,
+                error_code: ErrorCode::REFUSED_STREAM,
+            }),
+            receive(&mut connection, &headers_frame_bytes(5, Flags::NONE, b"\x82", None))
+        );
+        assert_eq!(StreamState::Closed, connection.stream_state(5));
+    }
+
+    #[test]
+    fn test_data_exceeding_window() {
+        let mut connection = Connection::new();
+        receive(&mut connection, &headers_frame_bytes(1, Flags::NONE, b"\x82", None)).unwrap();
+        let bytes = data_frame_bytes(1, &[0; 30_000], Some(5));
+        let (_, data) = Frame::parse(&bytes).unwrap();
+
+        assert!(connection.consume_frame(&data).is_ok());
+        assert!(connection.consume_frame(&data).is_ok());
+        assert_eq!(5_523, connection.recv_window(0));
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR)),
+            connection.consume_frame(&data)
+        );
+
+        connection.window_update_frame(0, 60_000).unwrap();
+        assert_eq!(
+            Err(Http2ParseError::Stream {
+                stream_identifier: 1,
+                error_code: ErrorCode::FLOW_CONTROL_ERROR,
+            }),
+            connection.consume_frame(&data)
+        );
+        assert_eq!(65_523, connection.recv_window(0));
+        assert_eq!(5_523, connection.recv_window(1));
+    }
+}
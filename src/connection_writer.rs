@@ -0,0 +1,195 @@
+use std::{io::Write, vec::Vec};
+
+use crate::{
+    error::Http2ParseError,
+    frames::{ErrorCode, Frame},
+    settings::{Http2Settings, MIN_MAX_FRAME_SIZE},
+    writers::client_handshake,
+};
+
+/// Writes the frames of one connection to a [`Write`], checking each against the frame size
+/// the peer accepts and the stream identifier rules before any of it is written.
+#[derive(Debug)]
+pub struct ConnectionWriter<W> {
+    inner: W,
+    is_client: bool,
+    max_frame_size: u32,
+    /// The highest stream id this endpoint has opened or promised.
+    highest_opened: u32,
+}
+
+impl<W: Write> ConnectionWriter<W> {
+    /// Starts a client connection by writing the preface and a SETTINGS frame for `settings`.
+    pub fn client(inner: W, settings: &Http2Settings) -> Result<Self, Http2ParseError> {
+        Self::start(inner, true, settings)
+    }
+
+    /// Starts a server connection by writing a SETTINGS frame for `settings`.
+    pub fn server(inner: W, settings: &Http2Settings) -> Result<Self, Http2ParseError> {
+        Self::start(inner, false, settings)
+    }
+
+    fn start(
+        mut inner: W,
+        is_client: bool,
+        settings: &Http2Settings,
+    ) -> Result<Self, Http2ParseError> {
+        let handshake = if is_client {
+            client_handshake(settings)
+        } else {
+            let mut buf = Vec::new();
+            settings.to_frame().write(&mut buf)?;
+            buf
+        };
+        inner.write_all(&handshake)?;
+
+        Ok(Self {
+            inner,
+            is_client,
+            max_frame_size: MIN_MAX_FRAME_SIZE,
+            highest_opened: 0,
+        })
+    }
+
+    /// Sets the largest frame payload to write, as advertised by the peer's
+    /// `SETTINGS_MAX_FRAME_SIZE`.
+    pub fn set_max_frame_size(&mut self, max_frame_size: u32) {
+        self.max_frame_size = max_frame_size;
+    }
+
+    /// Checks and writes one frame.
+    ///
+    /// Frames larger than the peer's maximum frame size are rejected with
+    /// [`Http2ParseError::FrameSize`]. Clients may only send HEADERS on streams they initiate,
+    /// and only servers may send PUSH_PROMISE, promising an even stream id above every stream
+    /// they opened before; other frames break these rules with `PROTOCOL_ERROR`.
+    pub fn write_frame(&mut self, frame: &Frame) -> Result<(), Http2ParseError> {
+        let header = frame.header();
+        if header.length.length() > self.max_frame_size {
+            return Err(Http2ParseError::FrameSize);
+        }
+        header.validate_stream_identifier()?;
+
+        let stream_id = header.stream_identifier.stream_identifier();
+        let protocol_error = Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR);
+        let opened = match frame {
+            Frame::Headers(..) if self.is_client && !self.is_local(stream_id) => {
+                return Err(protocol_error);
+            }
+            Frame::Headers(..) if self.is_local(stream_id) && stream_id > self.highest_opened => {
+                Some(stream_id)
+            }
+            Frame::PushPromise(_, push_promise) => {
+                let promised_id = push_promise.promised_stream_identifier.stream_identifier();
+                if self.is_client
+                    || !self.is_local(promised_id)
+                    || promised_id <= self.highest_opened
+                {
+                    return Err(protocol_error);
+                }
+                Some(promised_id)
+            }
+            _ => None,
+        };
+
+        let mut buf = Vec::with_capacity(9 + frame.payload_len());
+        frame.write(&mut buf)?;
+        self.inner.write_all(&buf)?;
+        if let Some(stream_id) = opened {
+            self.highest_opened = stream_id;
+        }
+        Ok(())
+    }
+
+    /// Whether this endpoint initiates `stream_id`: odd ids for clients, even ids for servers.
+    fn is_local(&self, stream_id: u32) -> bool {
+        (stream_id % 2 == 1) == self.is_client
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod connection_writer_tests {
+    use std::vec::Vec;
+
+    use crate::{
+        connection::CONNECTION_PREFACE,
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{ErrorCode, Frame, FrameHeader, FrameType, PingFrame, SettingsParameter},
+        settings::{Http2Settings, MaxFrameSize},
+        test_corpus::{headers_frame_bytes, push_promise_frame_bytes},
+    };
+
+    use super::ConnectionWriter;
+
+    #[test]
+    fn test_preface_settings_ping_round_trip() {
+        let settings = Http2Settings {
+            max_frame_size: MaxFrameSize::try_from(32_768).unwrap(),
+            ..Http2Settings::default()
+        };
+        let mut writer = ConnectionWriter::client(Vec::new(), &settings).unwrap();
+        let ping = Frame::Ping(
+            FrameHeader::new(FrameType::PING, Flags::NONE, 0, 8).unwrap(),
+            PingFrame::from_bytes(*b"htoo-ok!"),
+        );
+        writer.write_frame(&ping).unwrap();
+
+        let buf = writer.into_inner();
+        let bytes = buf.strip_prefix(CONNECTION_PREFACE).unwrap();
+        let (bytes, frame) = Frame::parse(bytes).unwrap();
+        let Frame::Settings(_, parsed_settings) = frame else {
+            panic!("expected a SETTINGS frame");
+        };
+        assert_eq!(
+            [(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 32_768)],
+            parsed_settings.into_iter().collect::<Vec<_>>().as_slice()
+        );
+        let (bytes, frame) = Frame::parse(bytes).unwrap();
+        assert_eq!(ping, frame);
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn test_write_frame_rules() {
+        let mut writer = ConnectionWriter::client(Vec::new(), &Http2Settings::default()).unwrap();
+        let written = writer.get_ref().len();
+
+        let oversized = Frame::Unknown(
+            FrameHeader::new(FrameType::UNKNOWN(0x42), Flags::NONE, 0, 16_385).unwrap(),
+            &[0; 16_385],
+        );
+        assert_eq!(Err(Http2ParseError::FrameSize), writer.write_frame(&oversized));
+
+        let protocol_error = Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+        for (bytes, expected) in [
+            (headers_frame_bytes(3, Flags::NONE, b"\x82", None), Ok(())),
+            (headers_frame_bytes(2, Flags::NONE, b"\x82", None), protocol_error),
+            (push_promise_frame_bytes(3, 4, b"\x82"), protocol_error),
+        ] {
+            let (_, frame) = Frame::parse(&bytes).unwrap();
+            assert_eq!(expected, writer.write_frame(&frame));
+        }
+        assert_eq!(written + 10, writer.get_ref().len());
+
+        let mut writer = ConnectionWriter::server(Vec::new(), &Http2Settings::default()).unwrap();
+        assert!(writer.get_ref().starts_with(&[0, 0, 0, 4]));
+        for (bytes, expected) in [
+            (headers_frame_bytes(1, Flags::NONE, b"\x88", None), Ok(())),
+            (push_promise_frame_bytes(1, 4, b"\x82"), Ok(())),
+            (push_promise_frame_bytes(1, 2, b"\x82"), protocol_error),
+            (push_promise_frame_bytes(1, 5, b"\x82"), protocol_error),
+        ] {
+            let (_, frame) = Frame::parse(&bytes).unwrap();
+            assert_eq!(expected, writer.write_frame(&frame));
+        }
+    }
+}
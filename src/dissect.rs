@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+
+use crate::{error::Http2ParseError, frames::Frame};
+
+/// The location of every header and payload field of one frame within its input buffer.
+///
+/// Each entry is `(field_name, start, len)`, with `start` relative to the first header byte.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Dissection {
+    pub fields: Vec<(&'static str, usize, usize)>,
+}
+
+impl Dissection {
+    /// Returns the `(start, len)` of the first field with the given name.
+    pub fn field(&self, name: &str) -> Option<(usize, usize)> {
+        self.fields
+            .iter()
+            .find(|(field_name, ..)| *field_name == name)
+            .map(|&(_, start, len)| (start, len))
+    }
+
+    fn push(&mut self, name: &'static str, len: usize) {
+        let start = self.fields.last().map_or(0, |&(_, start, len)| start + len);
+        self.fields.push((name, start, len));
+    }
+
+    fn push_optional(&mut self, name: &'static str, len: Option<usize>) {
+        if let Some(len) = len {
+            self.push(name, len);
+        }
+    }
+}
+
+impl Frame<'_> {
+    /// Parses one frame and records where each of its fields lives in `bytes`.
+    pub fn dissect(bytes: &[u8]) -> Result<Dissection, Http2ParseError> {
+        let (_, frame) = Frame::parse(bytes)?;
+        let mut dissection = Dissection::default();
+
+        dissection.push("length", 3);
+        dissection.push("type", 1);
+        dissection.push("flags", 1);
+        dissection.push("stream_identifier", 4);
+
+        match frame {
+            Frame::Data(_, frame) => {
+                dissection.push_optional("pad_length", frame.pad_length.map(|_| 1));
+                dissection.push("data", frame.data.len());
+                dissection.push_optional("padding", frame.padding.map(<[u8]>::len));
+            }
+            Frame::Headers(_, frame) => {
+                dissection.push_optional("pad_length", frame.pad_length.map(|_| 1));
+                dissection.push_optional("stream_dependency", frame.stream_dependency.map(|_| 4));
+                dissection.push_optional("weight", frame.weight.map(|_| 1));
+                dissection.push("header_block_fragment", frame.header_block_fragment.len());
+                dissection.push_optional("padding", frame.padding.map(<[u8]>::len));
+            }
+            Frame::Priority(..) => {
+                dissection.push("stream_dependency", 4);
+                dissection.push("weight", 1);
+            }
+            Frame::RstStream(..) => dissection.push("error_code", 4),
+            Frame::Settings(_, frame) => {
+                for _ in frame.parameters.unwrap_or_default() {
+                    dissection.push("parameter_identifier", 2);
+                    dissection.push("parameter_value", 4);
+                }
+            }
+            Frame::PushPromise(_, frame) => {
+                dissection.push_optional("pad_length", frame.pad_length.map(|_| 1));
+                dissection.push("promised_stream_identifier", 4);
+                dissection.push("header_block_fragment", frame.header_block_fragment.len());
+                dissection.push_optional("padding", frame.padding.map(<[u8]>::len));
+            }
+            Frame::Ping(..) => dissection.push("opaque_data", 8),
+            Frame::GoAway(_, frame) => {
+                dissection.push("last_stream_identifier", 4);
+                dissection.push("error_code", 4);
+                dissection.push_optional("debug_data", frame.debug_data.map(<[u8]>::len));
+            }
+            Frame::WindowUpdate(..) => dissection.push("window_size_increment", 4),
+            Frame::Continuation(_, frame) => {
+                dissection.push("header_block_fragment", frame.header_block_fragment.len());
+            }
+            Frame::AltSvc(_, frame) => {
+                dissection.push("origin_length", 2);
+                dissection.push("origin", frame.origin.len());
+                dissection.push("alt_svc_field_value", frame.field_value.len());
+            }
+            Frame::Origin(_, frame) => dissection.push("origin_entries", frame.payload.len()),
+            Frame::Ignored(_, payload) | Frame::Unknown(_, payload) => {
+                dissection.push("payload", payload.len());
+            }
+        }
+
+        Ok(dissection)
+    }
+}
+
+#[cfg(test)]
+mod dissect_tests {
+    use crate::{
+        frames::{ErrorCode, Frame},
+        test_corpus::goaway_frame_bytes,
+    };
+
+    #[test]
+    fn test_dissect_field_offsets() {
+        let bytes = goaway_frame_bytes(7, ErrorCode::NO_ERROR, b"bye");
+        let dissection = Frame::dissect(&bytes).unwrap();
+
+        assert_eq!(Some((0, 3)), dissection.field("length"));
+        assert_eq!(Some((5, 4)), dissection.field("stream_identifier"));
+        assert_eq!(Some((9, 4)), dissection.field("last_stream_identifier"));
+        assert_eq!(Some((13, 4)), dissection.field("error_code"));
+        assert_eq!(Some((17, 3)), dissection.field("debug_data"));
+        assert_eq!(None, dissection.field("padding"));
+    }
+}
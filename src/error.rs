@@ -0,0 +1,149 @@
+use crate::frames::{ErrorCode, UnknownFrameType};
+
+/// Errors produced while parsing or validating HTTP/2 frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Http2ParseError {
+    /// A connection error (RFC 7540 §5.4.1), answered with a GOAWAY carrying the error code.
+    Connection(ErrorCode),
+    /// A stream error (RFC 7540 §5.4.2), answered with a RST_STREAM on the offending stream.
+    Stream {
+        stream_identifier: u32,
+        error_code: ErrorCode,
+    },
+    /// The input ended early; `needed` is the number of additional bytes required, or 0 if unknown.
+    Incomplete { needed: usize },
+    /// A length did not fit the width of the field or frame that carries it.
+    FrameSize,
+    /// The input could not be decoded; carries the kind of the failing nom parser.
+    Malformed(nom::error::ErrorKind),
+    /// Like [`Malformed`](Self::Malformed), but also locating the failure: `offset` is where
+    /// the failing parser started, relative to the start of the input. Produced by
+    /// [`Http2ParseError::from_nom_at`] for debugging.
+    Nom {
+        kind: nom::error::ErrorKind,
+        offset: usize,
+    },
+    /// A frame of an unrecognized type was rejected under strict parse options.
+    UnknownFrameType(u8),
+    /// Reading from or writing to the underlying transport failed.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl Http2ParseError {
+    /// The HTTP/2 error code to report to the peer for this error.
+    pub fn error_code(&self) -> ErrorCode {
+        match self {
+            Self::Connection(error_code) | Self::Stream { error_code, .. } => *error_code,
+            Self::FrameSize => ErrorCode::FRAME_SIZE_ERROR,
+            Self::Incomplete { .. }
+            | Self::Malformed(_)
+            | Self::Nom { .. }
+            | Self::UnknownFrameType(_) => ErrorCode::PROTOCOL_ERROR,
+            #[cfg(feature = "std")]
+            Self::Io(_) => ErrorCode::INTERNAL_ERROR,
+        }
+    }
+}
+
+impl Http2ParseError {
+    /// Converts a nom error as the `From` impl does, except that a parser failure keeps its
+    /// kind and the offset of the failing slice within `input` as [`Http2ParseError::Nom`].
+    pub fn from_nom_at(input: &[u8], err: nom::Err<nom::error::Error<&[u8]>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e)
+                if e.code != nom::error::ErrorKind::TooLarge =>
+            {
+                let offset = (e.input.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+                Self::Nom {
+                    kind: e.code,
+                    offset,
+                }
+            }
+            err => err.into(),
+        }
+    }
+}
+
+impl From<nom::Err<nom::error::Error<&[u8]>>> for Http2ParseError {
+    fn from(err: nom::Err<nom::error::Error<&[u8]>>) -> Self {
+        match err {
+            nom::Err::Incomplete(nom::Needed::Size(needed)) => Self::Incomplete {
+                needed: needed.get(),
+            },
+            nom::Err::Incomplete(nom::Needed::Unknown) => Self::Incomplete { needed: 0 },
+            nom::Err::Error(e) | nom::Err::Failure(e)
+                if e.code == nom::error::ErrorKind::TooLarge =>
+            {
+                Self::FrameSize
+            }
+            nom::Err::Error(e) | nom::Err::Failure(e) => Self::Malformed(e.code),
+        }
+    }
+}
+
+impl From<UnknownFrameType> for Http2ParseError {
+    fn from(err: UnknownFrameType) -> Self {
+        Self::UnknownFrameType(err.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Http2ParseError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err.kind())
+    }
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::Http2ParseError;
+
+    #[test]
+    fn test_from_nom_incomplete() {
+        let err: nom::Err<nom::error::Error<&[u8]>> = nom::Err::Incomplete(nom::Needed::new(4));
+        assert_eq!(Http2ParseError::Incomplete { needed: 4 }, err.into());
+
+        let err: nom::Err<nom::error::Error<&[u8]>> =
+            nom::Err::Error(nom::error::Error::new(&[], nom::error::ErrorKind::Eof));
+        assert_eq!(
+            Http2ParseError::Malformed(nom::error::ErrorKind::Eof),
+            err.into()
+        );
+    }
+
+    #[test]
+    fn test_from_nom_at_offset() {
+        use crate::frames::{FrameHeader, PingFrame};
+
+        let bytes = [0x00, 0x00, 0x08, 0x06, 0x00];
+        let err = FrameHeader::parse(&bytes).unwrap_err();
+        assert_eq!(
+            Http2ParseError::Nom {
+                kind: nom::error::ErrorKind::Eof,
+                offset: 0,
+            },
+            Http2ParseError::from_nom_at(&bytes, err)
+        );
+
+        let bytes = [0x00, 0x00, 0x08, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02];
+        let err = PingFrame::parse(&bytes[9..]).unwrap_err();
+        assert_eq!(
+            Http2ParseError::Nom {
+                kind: nom::error::ErrorKind::Eof,
+                offset: 9,
+            },
+            Http2ParseError::from_nom_at(&bytes, err)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_io_error() {
+        let err = std::io::Error::from(std::io::ErrorKind::UnexpectedEof);
+        assert_eq!(
+            Http2ParseError::Io(std::io::ErrorKind::UnexpectedEof),
+            err.into()
+        );
+    }
+}
@@ -1,8 +1,11 @@
 use bitflags::bitflags;
 use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
 
+use crate::frames::FrameType;
+
 #[repr(transparent)]
-#[derive(Debug, Default, PartialEq, Eq, IntoBytes, FromBytes, KnownLayout, Immutable)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, IntoBytes, FromBytes, KnownLayout, Immutable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Flags(pub u8);
 
 bitflags! {
@@ -28,8 +31,152 @@ impl From<u8> for Flags {
     }
 }
 
+/// Renders [`Flags`] by name for a given frame type, e.g. `END_STREAM|END_HEADERS`.
+///
+/// The type decides whether `0x01` reads as `ACK` (SETTINGS, PING) or `END_STREAM`; bits without
+/// a name are rendered in hex, and an empty set renders as `NONE`.
+pub struct FlagsDisplay {
+    flags: Flags,
+    frame_type: FrameType,
+}
+
+impl Flags {
+    /// Clears every bit that `frame_type` does not define.
+    pub fn sanitized_for(&self, frame_type: FrameType) -> Flags {
+        *self & frame_type.valid_flags()
+    }
+
+    pub fn display_for(&self, frame_type: FrameType) -> FlagsDisplay {
+        FlagsDisplay {
+            flags: *self,
+            frame_type,
+        }
+    }
+}
+
+impl core::fmt::Display for FlagsDisplay {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.flags.is_empty() {
+            return f.write_str("NONE");
+        }
+
+        let first_flag = match self.frame_type {
+            FrameType::SETTINGS | FrameType::PING => "ACK",
+            _ => "END_STREAM",
+        };
+        let names = [
+            (Flags::END_STREAM, first_flag),
+            (Flags::END_HEADERS, "END_HEADERS"),
+            (Flags::PADDED, "PADDED"),
+            (Flags::PRIORITY, "PRIORITY"),
+        ];
+
+        let mut separator = "";
+        for (flag, name) in names {
+            if self.flags.contains(flag) {
+                write!(f, "{separator}{name}")?;
+                separator = "|";
+            }
+        }
+
+        let unnamed = self.flags.bits() & !Flags::all().bits();
+        if unnamed != 0 {
+            write!(f, "{separator}0x{unnamed:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Entry point for building the flags of one frame type, exposing only the flags that type
+/// defines, e.g. `FlagsBuilder::for_headers().end_stream().end_headers().build()`.
+///
+/// This sidesteps the `0x01` overlap: `ack()` exists only for SETTINGS and PING, and
+/// `end_stream()` only for DATA and HEADERS.
+#[derive(Debug, Clone, Copy)]
+pub struct FlagsBuilder;
+
+impl FlagsBuilder {
+    pub fn for_data() -> DataFlagsBuilder {
+        DataFlagsBuilder::default()
+    }
+
+    pub fn for_headers() -> HeadersFlagsBuilder {
+        HeadersFlagsBuilder::default()
+    }
+
+    pub fn for_settings() -> AckFlagsBuilder {
+        AckFlagsBuilder::default()
+    }
+
+    pub fn for_push_promise() -> PushPromiseFlagsBuilder {
+        PushPromiseFlagsBuilder::default()
+    }
+
+    pub fn for_ping() -> AckFlagsBuilder {
+        AckFlagsBuilder::default()
+    }
+
+    pub fn for_continuation() -> ContinuationFlagsBuilder {
+        ContinuationFlagsBuilder::default()
+    }
+}
+
+macro_rules! flags_builder {
+    ($(#[$doc:meta])* $name:ident { $($setter:ident => $flag:ident),+ $(,)? }) => {
+        $(#[$doc])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct $name(Flags);
+
+        impl $name {
+            $(
+                #[doc = concat!("Sets `", stringify!($flag), "`.")]
+                pub fn $setter(self) -> Self {
+                    Self(self.0 | Flags::$flag)
+                }
+            )+
+
+            pub fn build(self) -> Flags {
+                self.0
+            }
+        }
+    };
+}
+
+flags_builder! {
+    /// Builds DATA flags.
+    DataFlagsBuilder { end_stream => END_STREAM, padded => PADDED }
+}
+
+flags_builder! {
+    /// Builds HEADERS flags.
+    HeadersFlagsBuilder {
+        end_stream => END_STREAM,
+        end_headers => END_HEADERS,
+        padded => PADDED,
+        priority => PRIORITY,
+    }
+}
+
+flags_builder! {
+    /// Builds SETTINGS or PING flags, whose only flag is ACK.
+    AckFlagsBuilder { ack => ACK }
+}
+
+flags_builder! {
+    /// Builds PUSH_PROMISE flags.
+    PushPromiseFlagsBuilder { end_headers => END_HEADERS, padded => PADDED }
+}
+
+flags_builder! {
+    /// Builds CONTINUATION flags.
+    ContinuationFlagsBuilder { end_headers => END_HEADERS }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+
     use super::*;
 
     #[test]
@@ -53,4 +200,58 @@ mod tests {
         assert_eq!(&Flags::END_STREAM, flag4);
         assert_eq!(&Flags::ACK, flag4);
     }
+
+    #[test]
+    fn test_valid_flags_and_sanitize() {
+        let all = Flags(0xFF);
+        let table = [
+            (FrameType::DATA, 0x09),
+            (FrameType::HEADERS, 0x2D),
+            (FrameType::PRIORITY, 0x00),
+            (FrameType::RST_STREAM, 0x00),
+            (FrameType::SETTINGS, 0x01),
+            (FrameType::PUSH_PROMISE, 0x0C),
+            (FrameType::PING, 0x01),
+            (FrameType::GOAWAY, 0x00),
+            (FrameType::WINDOW_UPDATE, 0x00),
+            (FrameType::CONTINUATION, 0x04),
+            (FrameType::ALTSVC, 0x00),
+            (FrameType::ORIGIN, 0x00),
+            (FrameType::UNKNOWN(0x42), 0x00),
+        ];
+
+        for (frame_type, bits) in table {
+            assert_eq!(Flags(bits), frame_type.valid_flags(), "{frame_type}");
+            assert_eq!(Flags(bits), all.sanitized_for(frame_type), "{frame_type}");
+        }
+        assert_eq!(
+            Flags::END_STREAM,
+            (Flags::END_STREAM | Flags::PRIORITY).sanitized_for(FrameType::DATA)
+        );
+    }
+
+    #[test]
+    fn test_flags_builder() {
+        let flags = FlagsBuilder::for_headers()
+            .end_stream()
+            .end_headers()
+            .priority()
+            .padded()
+            .build();
+        assert_eq!(Flags(0x2D), flags);
+        assert_eq!(FrameType::HEADERS.valid_flags(), flags);
+
+        assert_eq!(Flags::END_STREAM, FlagsBuilder::for_data().end_stream().build());
+        assert_eq!(Flags::ACK, FlagsBuilder::for_ping().ack().build());
+        assert_eq!(Flags::NONE, FlagsBuilder::for_continuation().build());
+    }
+
+    #[test]
+    fn test_flags_display_for() {
+        let flags = Flags::END_STREAM | Flags::END_HEADERS;
+        assert_eq!("END_STREAM|END_HEADERS", flags.display_for(FrameType::HEADERS).to_string());
+        assert_eq!("ACK", Flags::ACK.display_for(FrameType::PING).to_string());
+        assert_eq!("NONE", Flags::NONE.display_for(FrameType::DATA).to_string());
+        assert_eq!("PADDED|0x40", Flags(0x48).display_for(FrameType::DATA).to_string());
+    }
 }
@@ -0,0 +1,157 @@
+use std::{io::Read, vec, vec::Vec};
+
+use crate::{
+    error::Http2ParseError,
+    frames::Frame,
+    parsers::frame_total_len,
+    settings::MIN_MAX_FRAME_SIZE,
+};
+
+/// Reads frames from a [`Read`] into one reusable buffer, handing out frames that borrow it.
+///
+/// The bytes of a consumed frame are dropped by moving any read-ahead to the front of the
+/// buffer, which grows only when a frame does not fit its current size.
+#[derive(Debug)]
+pub struct FrameReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    /// The number of bytes of `buf` holding data read from `inner`.
+    filled: usize,
+    /// The length of the frame last returned, dropped from the front on the next read.
+    consumed: usize,
+    max_frame_size: u32,
+}
+
+impl<R: Read> FrameReader<R> {
+    /// Creates a reader whose buffer holds one frame of the default maximum size.
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(inner, 9 + MIN_MAX_FRAME_SIZE as usize)
+    }
+
+    /// Creates a reader whose buffer starts at `capacity` bytes (at least one frame header).
+    pub fn with_capacity(inner: R, capacity: usize) -> Self {
+        Self {
+            inner,
+            buf: vec![0; capacity.max(9)],
+            filled: 0,
+            consumed: 0,
+            max_frame_size: MIN_MAX_FRAME_SIZE,
+        }
+    }
+
+    /// Sets the largest frame payload to accept, as advertised in this endpoint's
+    /// `SETTINGS_MAX_FRAME_SIZE`.
+    pub fn set_max_frame_size(&mut self, max_frame_size: u32) {
+        self.max_frame_size = max_frame_size;
+    }
+
+    /// The current size of the internal buffer.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Reads and parses the next frame.
+    ///
+    /// Returns `Ok(None)` when the input ends between frames, and an `UnexpectedEof`
+    /// [`Http2ParseError::Io`] when it ends inside one. A frame declaring a payload larger than
+    /// the maximum frame size fails with [`Http2ParseError::FrameSize`] before it is buffered.
+    pub fn read_frame(&mut self) -> Result<Option<Frame<'_>>, Http2ParseError> {
+        self.buf.copy_within(self.consumed..self.filled, 0);
+        self.filled -= self.consumed;
+        self.consumed = 0;
+
+        if !self.fill_to(9)? {
+            return Ok(None);
+        }
+        let header: &[u8; 9] = self.buf[..9].try_into().expect("nine bytes were read");
+        let total_len = frame_total_len(header);
+        if total_len - 9 > self.max_frame_size as usize {
+            return Err(Http2ParseError::FrameSize);
+        }
+        if total_len > self.buf.len() {
+            self.buf.resize(total_len, 0);
+        }
+        if !self.fill_to(total_len)? {
+            return Err(Http2ParseError::Io(std::io::ErrorKind::UnexpectedEof));
+        }
+
+        self.consumed = total_len;
+        let (_, frame) = Frame::parse(&self.buf[..total_len])?;
+        Ok(Some(frame))
+    }
+
+    /// Reads until at least `len` bytes are buffered. Returns `false` if the input ends first
+    /// without any bytes buffered, and an `UnexpectedEof` error if it ends after some.
+    fn fill_to(&mut self, len: usize) -> Result<bool, Http2ParseError> {
+        while self.filled < len {
+            match self.inner.read(&mut self.buf[self.filled..]) {
+                Ok(0) if self.filled == 0 => return Ok(false),
+                Ok(0) => return Err(Http2ParseError::Io(std::io::ErrorKind::UnexpectedEof)),
+                Ok(n) => self.filled += n,
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod frame_reader_tests {
+    use std::{io::Cursor, vec::Vec};
+
+    use crate::{
+        error::Http2ParseError,
+        frames::{Frame, FrameType},
+        test_corpus::{data_frame_bytes, ping_frame_bytes},
+    };
+
+    use super::FrameReader;
+
+    #[test]
+    fn test_many_small_frames() {
+        let mut bytes = Vec::new();
+        for i in 0..100u32 {
+            bytes.extend(ping_frame_bytes(u64::from(i), false));
+            bytes.extend(data_frame_bytes(1, &i.to_be_bytes(), None));
+        }
+        let mut reader = FrameReader::with_capacity(Cursor::new(bytes), 16);
+
+        for i in 0..100u32 {
+            let frame = reader.read_frame().unwrap().unwrap();
+            let Frame::Ping(_, ping) = frame else {
+                panic!("expected a PING frame");
+            };
+            assert_eq!(u64::from(i), ping.opaque_data);
+            let frame = reader.read_frame().unwrap().unwrap();
+            assert_eq!(FrameType::DATA, frame.header().frame_type);
+            assert_eq!(Some(&i.to_be_bytes()[..]), frame.payload());
+        }
+        assert_eq!(None, reader.read_frame().unwrap());
+        // The buffer grew once, to the largest frame (17 bytes), and was reused from then on.
+        assert_eq!(17, reader.capacity());
+    }
+
+    #[test]
+    fn test_truncated_and_oversized_frames() {
+        let mut bytes = data_frame_bytes(1, b"hello", None);
+        bytes.pop();
+        let mut reader = FrameReader::new(Cursor::new(bytes));
+        assert_eq!(
+            Err(Http2ParseError::Io(std::io::ErrorKind::UnexpectedEof)),
+            reader.read_frame()
+        );
+
+        let bytes = data_frame_bytes(1, &[0; 16_385], None);
+        let mut reader = FrameReader::new(Cursor::new(bytes));
+        assert_eq!(Err(Http2ParseError::FrameSize), reader.read_frame());
+    }
+}
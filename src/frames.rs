@@ -1,7 +1,10 @@
 use bitfield_struct::bitfield;
-use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+use zerocopy::{
+    FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned,
+    byteorder::network_endian::{U16, U32},
+};
 
-use crate::flags::Flags;
+use crate::{error::Http2ParseError, flags::Flags, options::ParseOptions};
 
 /// A 32-bit bitfield storing a 24-bit length and 8 bits of reserved space.
 /// 
@@ -9,6 +12,7 @@ use crate::flags::Flags;
 /// are unused or reserved for future use.
 #[bitfield(u32)]
 #[derive(PartialEq, Eq, FromBytes, IntoBytes, KnownLayout, Immutable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrameHeaderLength {
     #[bits(24)]
     pub length: u32,
@@ -17,10 +21,18 @@ pub struct FrameHeaderLength {
     _padding: u8,
 }
 
+impl FrameHeaderLength {
+    /// Decodes the 3-octet big-endian length field.
+    pub fn from_be_bytes(bytes: &[u8; 3]) -> Self {
+        Self::from_bits(u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]))
+    }
+}
+
 /// A 32-bit layout that reserves the first bit, with the remaining 31 bits used
 /// for the stream identifier. The `_reserved` field is unused.
-#[bitfield(u32)]
+#[bitfield(u32, order = Msb)]
 #[derive(PartialEq, Eq, FromBytes, IntoBytes, KnownLayout, Immutable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StreamIdentifier {
     #[bits(1)]
     pub _reserved: u8,
@@ -29,10 +41,18 @@ pub struct StreamIdentifier {
     pub stream_identifier: u32,
 }
 
+impl StreamIdentifier {
+    /// Decodes a big-endian stream identifier, keeping the reserved bit as received.
+    pub fn from_be_bytes(bytes: &[u8; 4]) -> Self {
+        Self::from_bits(u32::from_be_bytes(*bytes))
+    }
+}
+
 /// A 32-bit bitfield where the first bit indicates whether the dependency is exclusive, 
 /// and the remaining 31 bits store the stream identifier.
-#[bitfield(u32)]
+#[bitfield(u32, order = Msb)]
 #[derive(PartialEq, Eq, FromBytes, IntoBytes, KnownLayout, Immutable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StreamDependency {
     #[bits(1)]
     pub exclusive: bool,
@@ -41,10 +61,18 @@ pub struct StreamDependency {
     pub stream_identifier: u32,
 }
 
+impl StreamDependency {
+    /// Decodes a big-endian stream dependency, with the exclusive flag in the top bit.
+    pub fn from_be_bytes(bytes: &[u8; 4]) -> Self {
+        Self::from_bits(u32::from_be_bytes(*bytes))
+    }
+}
+
 /// A 32-bit bitfield with the first bit reserved, and the remaining 31 bits representing 
 /// the window size.
-#[bitfield(u32)]
+#[bitfield(u32, order = Msb)]
 #[derive(PartialEq, Eq, FromBytes, IntoBytes, KnownLayout, Immutable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindowSizeIncrement {
     #[bits(1)]
     pub _reserved: u8,
@@ -53,9 +81,29 @@ pub struct WindowSizeIncrement {
     pub window_size: u32,
 }
 
+impl WindowSizeIncrement {
+    /// Decodes a big-endian window size increment, keeping the reserved bit as received.
+    pub fn from_be_bytes(bytes: &[u8; 4]) -> Self {
+        Self::from_bits(u32::from_be_bytes(*bytes))
+    }
+
+    /// Returns the 31-bit increment. The reserved bit is ignored on receipt (RFC 7540 §6.9).
+    pub fn value(&self) -> u32 {
+        self.window_size()
+    }
+
+    /// Returns the increment widened to `i64`, the signed type used for window arithmetic since
+    /// a window may go negative after a SETTINGS_INITIAL_WINDOW_SIZE change.
+    pub fn as_i64(&self) -> i64 {
+        i64::from(self.value())
+    }
+}
+
 /// HTTP/2 error codes mapped to their 32-bit representation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
 #[repr(u32)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ErrorCode {
     /// **Code 0x0**
     ///
@@ -134,6 +182,34 @@ pub enum ErrorCode {
     UNKNOWN(u32),
 }
 
+impl ErrorCode {
+    /// Returns the error codes defined by RFC 7540 §7, in code order.
+    pub fn all_known() -> [ErrorCode; 14] {
+        core::array::from_fn(|code| Self::from(code as u32))
+    }
+
+    /// Returns the registered name of the error code, or `"UNKNOWN"` for unassigned codes.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NO_ERROR => "NO_ERROR",
+            Self::PROTOCOL_ERROR => "PROTOCOL_ERROR",
+            Self::INTERNAL_ERROR => "INTERNAL_ERROR",
+            Self::FLOW_CONTROL_ERROR => "FLOW_CONTROL_ERROR",
+            Self::SETTINGS_TIMEOUT => "SETTINGS_TIMEOUT",
+            Self::STREAM_CLOSED => "STREAM_CLOSED",
+            Self::FRAME_SIZE_ERROR => "FRAME_SIZE_ERROR",
+            Self::REFUSED_STREAM => "REFUSED_STREAM",
+            Self::CANCEL => "CANCEL",
+            Self::COMPRESSION_ERROR => "COMPRESSION_ERROR",
+            Self::CONNECT_ERROR => "CONNECT_ERROR",
+            Self::ENHANCE_YOUR_CALM => "ENHANCE_YOUR_CALM",
+            Self::INADEQUATE_SECURITY => "INADEQUATE_SECURITY",
+            Self::HTTP_1_1_REQUIRED => "HTTP_1_1_REQUIRED",
+            Self::UNKNOWN(_) => "UNKNOWN",
+        }
+    }
+}
+
 impl From<u32> for ErrorCode {
     fn from(value: u32) -> Self {
         match value {
@@ -156,13 +232,35 @@ impl From<u32> for ErrorCode {
     }
 }
 
+impl From<&ErrorCode> for u32 {
+    fn from(value: &ErrorCode) -> Self {
+        match value {
+            ErrorCode::NO_ERROR => 0x0,
+            ErrorCode::PROTOCOL_ERROR => 0x1,
+            ErrorCode::INTERNAL_ERROR => 0x2,
+            ErrorCode::FLOW_CONTROL_ERROR => 0x3,
+            ErrorCode::SETTINGS_TIMEOUT => 0x4,
+            ErrorCode::STREAM_CLOSED => 0x5,
+            ErrorCode::FRAME_SIZE_ERROR => 0x6,
+            ErrorCode::REFUSED_STREAM => 0x7,
+            ErrorCode::CANCEL => 0x8,
+            ErrorCode::COMPRESSION_ERROR => 0x9,
+            ErrorCode::CONNECT_ERROR => 0xa,
+            ErrorCode::ENHANCE_YOUR_CALM => 0xb,
+            ErrorCode::INADEQUATE_SECURITY => 0xc,
+            ErrorCode::HTTP_1_1_REQUIRED => 0xd,
+            ErrorCode::UNKNOWN(v) => *v,
+        }
+    }
+}
 
 /// Enumerates known HTTP/2 frame types as 8-bit values, including a variant for unknown types.
 /// 
 /// The default variant is `DATA` (`0x0`).
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[allow(non_camel_case_types)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FrameType {
     #[default]
     /// RFC 7540: Hypertext Transfer Protocol Version 2
@@ -193,6 +291,77 @@ pub enum FrameType {
     UNKNOWN(u8),
 }
 
+/// The error returned by [`FrameType::try_from_u8`] for an unassigned frame type byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownFrameType(pub u8);
+
+impl FrameType {
+    /// Converts a type byte, rejecting unassigned types instead of mapping them to `UNKNOWN`.
+    ///
+    /// This is not a `TryFrom<u8>` impl because the lenient `From<u8>` already provides an
+    /// infallible one.
+    pub fn try_from_u8(value: u8) -> Result<Self, UnknownFrameType> {
+        match Self::from(value) {
+            Self::UNKNOWN(value) => Err(UnknownFrameType(value)),
+            frame_type => Ok(frame_type),
+        }
+    }
+
+    /// Returns the registered name of the frame type, or `"UNKNOWN"` for unassigned types.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::DATA => "DATA",
+            Self::HEADERS => "HEADERS",
+            Self::PRIORITY => "PRIORITY",
+            Self::RST_STREAM => "RST_STREAM",
+            Self::SETTINGS => "SETTINGS",
+            Self::PUSH_PROMISE => "PUSH_PROMISE",
+            Self::PING => "PING",
+            Self::GOAWAY => "GOAWAY",
+            Self::WINDOW_UPDATE => "WINDOW_UPDATE",
+            Self::CONTINUATION => "CONTINUATION",
+            Self::ALTSVC => "ALTSVC",
+            Self::ORIGIN => "ORIGIN",
+            Self::UNKNOWN(_) => "UNKNOWN",
+        }
+    }
+
+    /// Returns the exact payload length of frame types whose payload has a fixed size, or
+    /// `None` for variable-length types.
+    pub fn fixed_payload_len(&self) -> Option<u32> {
+        match self {
+            Self::PRIORITY => Some(5),
+            Self::RST_STREAM | Self::WINDOW_UPDATE => Some(4),
+            Self::PING => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Returns the union of the flags this frame type defines; any other bit must be ignored
+    /// on receipt and left unset when sending (RFC 7540 §4.1).
+    pub fn valid_flags(&self) -> Flags {
+        match self {
+            Self::DATA => Flags::END_STREAM | Flags::PADDED,
+            Self::HEADERS => {
+                Flags::END_STREAM | Flags::END_HEADERS | Flags::PADDED | Flags::PRIORITY
+            }
+            Self::SETTINGS | Self::PING => Flags::ACK,
+            Self::PUSH_PROMISE => Flags::END_HEADERS | Flags::PADDED,
+            Self::CONTINUATION => Flags::END_HEADERS,
+            _ => Flags::NONE,
+        }
+    }
+}
+
+impl core::fmt::Display for FrameType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UNKNOWN(v) => write!(f, "UNKNOWN(0x{v:02x})"),
+            _ => f.write_str(self.name()),
+        }
+    }
+}
+
 impl From<u8> for FrameType {
     fn from(value: u8) -> Self {
         match value {
@@ -213,12 +382,33 @@ impl From<u8> for FrameType {
     }
 }
 
+impl From<&FrameType> for u8 {
+    fn from(value: &FrameType) -> Self {
+        match value {
+            FrameType::DATA => 0x0,
+            FrameType::HEADERS => 0x1,
+            FrameType::PRIORITY => 0x2,
+            FrameType::RST_STREAM => 0x3,
+            FrameType::SETTINGS => 0x4,
+            FrameType::PUSH_PROMISE => 0x5,
+            FrameType::PING => 0x6,
+            FrameType::GOAWAY => 0x7,
+            FrameType::WINDOW_UPDATE => 0x8,
+            FrameType::CONTINUATION => 0x9,
+            FrameType::ALTSVC => 0xa,
+            FrameType::ORIGIN => 0xc,
+            FrameType::UNKNOWN(v) => *v,
+        }
+    }
+}
+
 /// Enumerates 16-bit HTTP/2 SETTINGS parameters, with a variant for reserved codes.
 ///
 /// These parameters correspond to RFC 7540-defined values.
 #[allow(non_camel_case_types)]
 #[repr(u16)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SettingsParameter {
     SETTINGS_HEADER_TABLE_SIZE = 0x1,
     SETTINGS_ENABLE_PUSH = 0x2,
@@ -249,7 +439,64 @@ impl From<u16> for SettingsParameter {
     }
 }
 
+/// How the value of a SETTINGS parameter should be interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// `0` or `1`, rendered as `false` or `true`.
+    Boolean,
+    /// A size in octets.
+    Bytes,
+    /// A number of items, such as streams.
+    Count,
+    /// A reserved parameter whose meaning is unknown.
+    Opaque,
+}
+
+impl SettingsParameter {
+    /// Returns how this parameter's value should be interpreted.
+    pub fn value_kind(&self) -> ValueKind {
+        match self {
+            Self::SETTINGS_ENABLE_PUSH => ValueKind::Boolean,
+            Self::SETTINGS_HEADER_TABLE_SIZE
+            | Self::SETTINGS_INITIAL_WINDOW_SIZE
+            | Self::SETTINGS_MAX_FRAME_SIZE
+            | Self::SETTINGS_MAX_HEADER_LIST_SIZE => ValueKind::Bytes,
+            Self::SETTINGS_MAX_CONCURRENT_STREAMS => ValueKind::Count,
+            Self::RESERVED(_) => ValueKind::Opaque,
+        }
+    }
+}
+
+impl core::fmt::Display for SettingsParameter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::SETTINGS_HEADER_TABLE_SIZE => f.write_str("SETTINGS_HEADER_TABLE_SIZE"),
+            Self::SETTINGS_ENABLE_PUSH => f.write_str("SETTINGS_ENABLE_PUSH"),
+            Self::SETTINGS_MAX_CONCURRENT_STREAMS => f.write_str("SETTINGS_MAX_CONCURRENT_STREAMS"),
+            Self::SETTINGS_INITIAL_WINDOW_SIZE => f.write_str("SETTINGS_INITIAL_WINDOW_SIZE"),
+            Self::SETTINGS_MAX_FRAME_SIZE => f.write_str("SETTINGS_MAX_FRAME_SIZE"),
+            Self::SETTINGS_MAX_HEADER_LIST_SIZE => f.write_str("SETTINGS_MAX_HEADER_LIST_SIZE"),
+            Self::RESERVED(v) => write!(f, "RESERVED(0x{v:x})"),
+        }
+    }
+}
+
+impl From<&SettingsParameter> for u16 {
+    fn from(value: &SettingsParameter) -> Self {
+        match value {
+            SettingsParameter::SETTINGS_HEADER_TABLE_SIZE => 0x1,
+            SettingsParameter::SETTINGS_ENABLE_PUSH => 0x2,
+            SettingsParameter::SETTINGS_MAX_CONCURRENT_STREAMS => 0x3,
+            SettingsParameter::SETTINGS_INITIAL_WINDOW_SIZE => 0x4,
+            SettingsParameter::SETTINGS_MAX_FRAME_SIZE => 0x5,
+            SettingsParameter::SETTINGS_MAX_HEADER_LIST_SIZE => 0x6,
+            SettingsParameter::RESERVED(v) => *v,
+        }
+    }
+}
+
 /// An HTTP/2 DATA frame, containing optional padding and a payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DataFrame<'a> {
     pub pad_length: Option<u8>,
     pub data: &'a [u8],
@@ -257,6 +504,7 @@ pub struct DataFrame<'a> {
 }
 
 /// An HTTP/2 HEADERS frame, optionally including padding, stream dependency, and a weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct HeadersFrame<'a> {
     pub pad_length: Option<u8>,
     pub stream_dependency: Option<StreamDependency>,
@@ -265,41 +513,253 @@ pub struct HeadersFrame<'a> {
     pub padding: Option<&'a [u8]>,
 }
 
+impl HeadersFrame<'_> {
+    /// Whether the frame carried a Pad Length field, i.e. had PADDED set.
+    pub fn was_padded(&self) -> bool {
+        self.pad_length.is_some()
+    }
+
+    /// Whether the frame carried the stream dependency and weight fields, i.e. had PRIORITY set.
+    pub fn has_priority(&self) -> bool {
+        self.stream_dependency.is_some()
+    }
+}
+
 /// An HTTP/2 PRIORITY frame, indicating the stream dependency and weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PriorityFrame {
     pub stream_dependency: StreamDependency,
     pub weight: u8,
 }
 
 /// An HTTP/2 RST_STREAM frame, carrying an error code that explains why the stream is reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RstStreamFrame {
     pub error_code: ErrorCode,
 }
 
+impl RstStreamFrame {
+    pub fn new(error_code: ErrorCode) -> Self {
+        Self { error_code }
+    }
+
+    /// Returns the numeric error code with its name, `"UNKNOWN"` for unassigned codes, so
+    /// known and unknown codes can be logged alike.
+    pub fn reason(&self) -> (u32, &'static str) {
+        (u32::from(&self.error_code), self.error_code.name())
+    }
+}
+
 /// Represents a single parameter-value pair in a SETTINGS frame.
+///
+/// The layout mirrors the 6-octet big-endian wire format, so a SETTINGS payload can be viewed
+/// directly as a slice of these.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "(SettingsParameter, u32)", from = "(SettingsParameter, u32)")
+)]
 pub struct SettingsParameterFrame {
-    pub identifier: SettingsParameter,
-    pub value: u32,
+    identifier: U16,
+    value: U32,
+}
+
+impl SettingsParameterFrame {
+    pub fn new(identifier: SettingsParameter, value: u32) -> Self {
+        Self {
+            identifier: U16::new(u16::from(&identifier)),
+            value: U32::new(value),
+        }
+    }
+
+    pub fn identifier(&self) -> SettingsParameter {
+        SettingsParameter::from(self.identifier.get())
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value.get()
+    }
+}
+
+impl From<SettingsParameterFrame> for (SettingsParameter, u32) {
+    fn from(frame: SettingsParameterFrame) -> Self {
+        (frame.identifier(), frame.value())
+    }
+}
+
+impl From<(SettingsParameter, u32)> for SettingsParameterFrame {
+    fn from((identifier, value): (SettingsParameter, u32)) -> Self {
+        Self::new(identifier, value)
+    }
+}
+
+/// Renders the pair as `NAME = value`, interpreting the value by its [`ValueKind`].
+impl core::fmt::Display for SettingsParameterFrame {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let identifier = self.identifier();
+        match (identifier.value_kind(), self.value()) {
+            (ValueKind::Boolean, 0) => write!(f, "{identifier} = false"),
+            (ValueKind::Boolean, 1) => write!(f, "{identifier} = true"),
+            (_, value) => write!(f, "{identifier} = {value}"),
+        }
+    }
 }
 
 /// An HTTP/2 SETTINGS frame, containing zero or more parameter-value pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SettingsFrame<'a> {
     pub parameters: Option<&'a [SettingsParameterFrame]>,
 }
 
+/// Iterates the `(parameter, value)` pairs of a [`SettingsFrame`] in the order they were received.
+pub struct SettingsIter<'a> {
+    inner: core::slice::Iter<'a, SettingsParameterFrame>,
+}
+
+impl Iterator for SettingsIter<'_> {
+    type Item = (SettingsParameter, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|p| (p.identifier(), p.value()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a> IntoIterator for &'a SettingsFrame<'_> {
+    type Item = (SettingsParameter, u32);
+    type IntoIter = SettingsIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SettingsIter {
+            inner: self.parameters.unwrap_or_default().iter(),
+        }
+    }
+}
+
+/// A SETTINGS payload kept as raw bytes, decoding each `(parameter, value)` pair only as it is
+/// iterated. Built by `SettingsFrame::view`; an ACK has an empty payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingsView<'a> {
+    pub payload: &'a [u8],
+}
+
+/// Decodes the pairs of a [`SettingsView`] one 6-octet chunk at a time.
+pub struct SettingsViewIter<'a> {
+    inner: core::slice::ChunksExact<'a, u8>,
+}
+
+impl Iterator for SettingsViewIter<'_> {
+    type Item = (SettingsParameter, u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|chunk| {
+            let identifier = u16::from_be_bytes([chunk[0], chunk[1]]);
+            let value = u32::from_be_bytes([chunk[2], chunk[3], chunk[4], chunk[5]]);
+            (SettingsParameter::from(identifier), value)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for SettingsViewIter<'_> {}
+
+impl<'a> IntoIterator for SettingsView<'a> {
+    type Item = (SettingsParameter, u32);
+    type IntoIter = SettingsViewIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SettingsViewIter {
+            inner: self.payload.chunks_exact(6),
+        }
+    }
+}
+
+impl SettingsFrame<'_> {
+    /// Whether this is a non-ACK SETTINGS frame with no parameters, by which a peer announces
+    /// that it uses every default. An ACK, which also carries none, has `parameters: None`.
+    pub fn is_empty_settings(&self) -> bool {
+        self.parameters.is_some_and(<[SettingsParameterFrame]>::is_empty)
+    }
+
+    /// Compares the parameters of two frames as maps, ignoring their order. A parameter
+    /// repeated within a frame counts with its last value, as it would take effect.
+    pub fn semantically_eq(&self, other: &SettingsFrame) -> bool {
+        let last_value = |frame: &SettingsFrame, parameter| {
+            frame
+                .into_iter()
+                .filter(|(identifier, _)| *identifier == parameter)
+                .last()
+                .map(|(_, value)| value)
+        };
+
+        self.into_iter()
+            .chain(other)
+            .all(|(parameter, _)| last_value(self, parameter) == last_value(other, parameter))
+    }
+}
+
 /// An HTTP/2 PING frame, carrying opaque data used to measure round-trip time or other diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PingFrame {
     pub opaque_data: u64,
 }
 
+impl PingFrame {
+    /// Creates a PING frame from its 8 opaque octets as they appear on the wire.
+    pub fn from_bytes(opaque_bytes: [u8; 8]) -> Self {
+        Self {
+            opaque_data: u64::from_be_bytes(opaque_bytes),
+        }
+    }
+
+    /// Returns the opaque data as its 8 wire octets, for byte-wise comparison.
+    pub fn opaque_bytes(&self) -> [u8; 8] {
+        self.opaque_data.to_be_bytes()
+    }
+}
+
 /// An HTTP/2 GOAWAY frame, indicating that no further streams can be initiated on this connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GoAwayFrame<'a> {
     pub last_stream_identifier: StreamIdentifier,
     pub error_code: ErrorCode,
     pub debug_data: Option<&'a [u8]>,
 }
 
+impl<'a> GoAwayFrame<'a> {
+    /// Creates a GOAWAY frame, masking `last_stream_id` to 31 bits so the reserved bit is clear.
+    pub fn new(last_stream_id: u32, code: ErrorCode, debug: Option<&'a [u8]>) -> Self {
+        Self {
+            last_stream_identifier: StreamIdentifier::new()
+                .with_stream_identifier(last_stream_id & 0x7FFF_FFFF),
+            error_code: code,
+            debug_data: debug,
+        }
+    }
+
+    /// Builds the stream-0 header for this frame, failing with [`Http2ParseError::FrameSize`]
+    /// if the debug data does not fit the 24-bit length alongside the 8 fixed octets.
+    pub fn frame_header(&self) -> Result<FrameHeader, Http2ParseError> {
+        let length = u32::try_from(8 + self.debug_data.map_or(0, <[u8]>::len))
+            .map_err(|_| Http2ParseError::FrameSize)?;
+        FrameHeader::new(FrameType::GOAWAY, Flags::NONE, 0, length)
+            .ok_or(Http2ParseError::FrameSize)
+    }
+}
+
 /// An HTTP/2 PUSH_PROMISE frame, which reserves a stream in advance of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PushPromiseFrame<'a> {
     pub pad_length: Option<u8>,
     pub promised_stream_identifier: StreamIdentifier,
@@ -308,28 +768,103 @@ pub struct PushPromiseFrame<'a> {
 }
 
 /// An HTTP/2 WINDOW_UPDATE frame, used to increase the flow-control window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WindowUpdateFrame {
     pub window_size_increment: WindowSizeIncrement,
 }
 
+impl WindowUpdateFrame {
+    /// Creates a WINDOW_UPDATE frame, returning `None` unless `increment` is in `1..=2^31-1`.
+    pub fn new(increment: u32) -> Option<Self> {
+        if increment == 0 || increment > 0x7FFF_FFFF {
+            return None;
+        }
+
+        Some(Self {
+            window_size_increment: WindowSizeIncrement::new().with_window_size(increment),
+        })
+    }
+}
+
 /// An HTTP/2 CONTINUATION frame, extending a header block begun by a previous HEADERS or PUSH_PROMISE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ContinuationFrame<'a> {
     pub header_block_fragment: &'a [u8],
 }
 
 /// A single origin entry carried in an ORIGIN frame, containing its length and optional ASCII string.
+#[derive(Debug, PartialEq, Eq)]
 pub struct OriginEntry<'a> {
     pub origin_length: u16,
+    /// `None` when the entry is empty or contains bytes outside printable ASCII.
     pub ascii_origin: Option<&'a str>
 }
 
-/// An HTTP/2 ORIGIN frame, optionally carrying a single origin entry.
+impl<'a> OriginEntry<'a> {
+    /// Creates an entry for `origin`, deriving `origin_length` from it so the two cannot
+    /// disagree. The origin must be non-empty printable ASCII of at most `u16::MAX` bytes.
+    pub fn new(origin: &'a str) -> Result<Self, Http2ParseError> {
+        let origin_length = u16::try_from(origin.len()).map_err(|_| Http2ParseError::FrameSize)?;
+        if origin.is_empty() || !origin.bytes().all(|byte| byte.is_ascii_graphic()) {
+            return Err(Http2ParseError::Malformed(nom::error::ErrorKind::Alpha));
+        }
+
+        Ok(Self {
+            origin_length,
+            ascii_origin: Some(origin),
+        })
+    }
+
+    /// Whether the entry carries a usable ASCII origin; invalid entries must be ignored.
+    pub fn is_valid(&self) -> bool {
+        self.ascii_origin.is_some()
+    }
+}
+
+/// An HTTP/2 ORIGIN frame, holding its raw payload of zero or more origin entries.
+///
+/// Entries are decoded on demand through `OriginFrame::entries`, so no allocation is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OriginFrame<'a> {
-    pub origin_entry: Option<OriginEntry<'a>>
+    pub payload: &'a [u8],
+}
+
+/// Lazily decodes the origin entries of an [`OriginFrame`] payload, one per call to `next`.
+///
+/// Iteration stops after the first malformed entry.
+#[cfg(feature = "rfc8336")]
+pub struct OriginEntryIter<'a> {
+    pub(crate) remaining: &'a [u8],
+}
+
+/// An RFC 7838 ALTSVC frame: the origin it applies to and the Alt-Svc field value.
+///
+/// On stream 0 the origin names the origin being advertised for; on any other stream it is
+/// empty and the alternatives apply to that stream's origin (RFC 7838 §4).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AltSvcFrame<'a> {
+    pub origin: &'a [u8],
+    pub field_value: &'a [u8],
+}
+
+/// One alternative service from an Alt-Svc field value (RFC 7838 §3).
+///
+/// Values are borrowed from the frame as they appear on the wire; neither percent-encoding in
+/// `protocol_id` nor escapes in the quoted `authority` are decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AltSvcEntry<'a> {
+    pub protocol_id: &'a str,
+    pub authority: &'a str,
+    /// The `ma` parameter in seconds; `None` means the default of 24 hours.
+    pub max_age: Option<u32>,
+    /// Whether `persist=1` was given.
+    pub persist: bool,
 }
 
 /// The universal frame header for HTTP/2, containing length, type, flags, and stream ID.
-#[derive(Debug, Default, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrameHeader {
     pub length: FrameHeaderLength,
     pub frame_type: FrameType,
@@ -337,8 +872,51 @@ pub struct FrameHeader {
     pub stream_identifier: StreamIdentifier,
 }
 
+impl FrameHeader {
+    /// Creates a frame header, masking `stream_id` to 31 bits.
+    ///
+    /// Returns `None` if `length` does not fit in the 24-bit length field.
+    pub fn new(frame_type: FrameType, flags: Flags, stream_id: u32, length: u32) -> Option<Self> {
+        if length > 0xFF_FFFF {
+            return None;
+        }
+
+        Some(Self {
+            length: FrameHeaderLength::new().with_length(length),
+            frame_type,
+            flags,
+            stream_identifier: StreamIdentifier::new()
+                .with_stream_identifier(stream_id & 0x7FFF_FFFF),
+        })
+    }
+
+    /// Checks that the stream identifier suits the frame type: stream-level frames must not
+    /// use stream 0, and SETTINGS, PING, and GOAWAY must use it. Anything else is a
+    /// connection `PROTOCOL_ERROR`.
+    pub fn validate_stream_identifier(&self) -> Result<(), Http2ParseError> {
+        let on_stream_zero = self.stream_identifier.stream_identifier() == 0;
+        let valid = match self.frame_type {
+            FrameType::DATA
+            | FrameType::HEADERS
+            | FrameType::PRIORITY
+            | FrameType::RST_STREAM
+            | FrameType::PUSH_PROMISE
+            | FrameType::CONTINUATION => !on_stream_zero,
+            FrameType::SETTINGS | FrameType::PING | FrameType::GOAWAY => on_stream_zero,
+            _ => true,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR))
+        }
+    }
+}
+
 /// A high-level representation of any HTTP/2 frame, combining a [`FrameHeader`] 
 /// with the specific structure for that frame type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Frame<'a> {
     Data(FrameHeader, DataFrame<'a>),
     Headers(FrameHeader, HeadersFrame<'a>),
@@ -350,4 +928,487 @@ pub enum Frame<'a> {
     GoAway(FrameHeader, GoAwayFrame<'a>),
     WindowUpdate(FrameHeader, WindowUpdateFrame),
     Continuation(FrameHeader, ContinuationFrame<'a>),
+    /// An RFC 7838 ALTSVC frame; only produced when the `rfc7838` feature is enabled.
+    AltSvc(FrameHeader, AltSvcFrame<'a>),
+    /// An RFC 8336 ORIGIN frame; only produced when the `rfc8336` feature is enabled.
+    Origin(FrameHeader, OriginFrame<'a>),
+    /// A well-formed frame the protocol says to ignore, kept apart from errors so callers can
+    /// simply skip it: an ORIGIN frame off stream 0 (RFC 8336 §2), or an ALTSVC frame whose
+    /// origin does not suit its stream (RFC 7838 §4). It keeps its raw payload so that it can
+    /// still be forwarded.
+    Ignored(FrameHeader, &'a [u8]),
+    /// A frame of an unrecognized type, carried with its raw payload so it can be ignored or forwarded.
+    Unknown(FrameHeader, &'a [u8]),
+}
+
+/// The header-level facts about a frame, without its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameSummary {
+    pub frame_type: FrameType,
+    pub stream_identifier: u32,
+    pub flags: Flags,
+    pub length: u32,
+}
+
+/// A frame's declared payload length next to the length of the fields it was parsed into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthReport {
+    /// The length from the frame header.
+    pub declared: usize,
+    /// The sum of the parsed payload field lengths, as computed by [`Frame::payload_len`].
+    pub actual: usize,
+}
+
+impl LengthReport {
+    /// Whether the parsed fields do not account for exactly the declared length.
+    pub fn is_mismatch(&self) -> bool {
+        self.declared != self.actual
+    }
+}
+
+/// A parsed frame with the payload length its header declared and the number of payload octets
+/// its decoded fields account for, as returned by [`Frame::parse_detailed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedFrame<'a> {
+    pub frame: Frame<'a>,
+    pub declared_len: usize,
+    pub consumed_len: usize,
+}
+
+impl<'a> Frame<'a> {
+    /// Returns the frame header shared by every variant.
+    pub fn header(&self) -> &FrameHeader {
+        match self {
+            Self::Data(header, _)
+            | Self::Headers(header, _)
+            | Self::Priority(header, _)
+            | Self::RstStream(header, _)
+            | Self::Settings(header, _)
+            | Self::PushPromise(header, _)
+            | Self::Ping(header, _)
+            | Self::GoAway(header, _)
+            | Self::WindowUpdate(header, _)
+            | Self::Continuation(header, _)
+            | Self::AltSvc(header, _)
+            | Self::Origin(header, _)
+            | Self::Ignored(header, _)
+            | Self::Unknown(header, _) => header,
+        }
+    }
+
+    /// Returns the type, stream, flags, and declared payload length of this frame.
+    pub fn summary(&self) -> FrameSummary {
+        let header = self.header();
+        FrameSummary {
+            frame_type: header.frame_type,
+            stream_identifier: header.stream_identifier.stream_identifier(),
+            flags: header.flags,
+            length: header.length.length(),
+        }
+    }
+
+    /// Returns the payload length computed from the parsed fields, which matches the declared
+    /// length for a well-formed frame.
+    pub fn payload_len(&self) -> usize {
+        let optional_len = |slice: Option<&[u8]>| slice.map_or(0, <[u8]>::len);
+        let pad_length_len = |pad_length: Option<u8>| usize::from(pad_length.is_some());
+
+        match self {
+            Self::Data(_, frame) => {
+                pad_length_len(frame.pad_length) + frame.data.len() + optional_len(frame.padding)
+            }
+            Self::Headers(_, frame) => {
+                pad_length_len(frame.pad_length)
+                    + frame.stream_dependency.map_or(0, |_| 4)
+                    + usize::from(frame.weight.is_some())
+                    + frame.header_block_fragment.len()
+                    + optional_len(frame.padding)
+            }
+            Self::Priority(..) => 5,
+            Self::RstStream(..) | Self::WindowUpdate(..) => 4,
+            Self::Settings(_, frame) => frame.parameters.unwrap_or_default().len() * 6,
+            Self::PushPromise(_, frame) => {
+                pad_length_len(frame.pad_length)
+                    + 4
+                    + frame.header_block_fragment.len()
+                    + optional_len(frame.padding)
+            }
+            Self::Ping(..) => 8,
+            Self::GoAway(_, frame) => 8 + optional_len(frame.debug_data),
+            Self::Continuation(_, frame) => frame.header_block_fragment.len(),
+            Self::AltSvc(_, frame) => 2 + frame.origin.len() + frame.field_value.len(),
+            Self::Origin(_, frame) => frame.payload.len(),
+            Self::Ignored(_, payload) => payload.len(),
+            Self::Unknown(_, payload) => payload.len(),
+        }
+    }
+
+    /// Compares the header's declared length with the length of the parsed fields, to spot
+    /// frames built or decoded with fields that disagree with their header.
+    pub fn length_check(&self) -> LengthReport {
+        LengthReport {
+            declared: self.header().length.length() as usize,
+            actual: self.payload_len(),
+        }
+    }
+
+    /// Returns the frame's primary byte payload: DATA data, a header block fragment, GOAWAY
+    /// debug data, the ALTSVC field value, or the raw payload of ORIGIN, ignored, and unknown
+    /// frames.
+    /// Frames made only of fixed fields (and SETTINGS) return `None`, as does a GOAWAY without
+    /// debug data.
+    pub fn payload(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Data(_, frame) => Some(frame.data),
+            Self::Headers(_, frame) => Some(frame.header_block_fragment),
+            Self::PushPromise(_, frame) => Some(frame.header_block_fragment),
+            Self::Continuation(_, frame) => Some(frame.header_block_fragment),
+            Self::GoAway(_, frame) => frame.debug_data,
+            Self::AltSvc(_, frame) => Some(frame.field_value),
+            Self::Origin(_, frame) => Some(frame.payload),
+            Self::Ignored(_, payload) | Self::Unknown(_, payload) => Some(payload),
+            Self::Priority(..)
+            | Self::RstStream(..)
+            | Self::Settings(..)
+            | Self::Ping(..)
+            | Self::WindowUpdate(..) => None,
+        }
+    }
+
+    /// Reports whether the raw flags set any bit this frame's type does not define.
+    ///
+    /// Parsing keeps such bits as received; use [`Flags::sanitized_for`] to clear them. Unknown
+    /// frame types define no flags of their own, so their flags are never reported.
+    pub fn has_invalid_flag_bits(&self) -> bool {
+        let header = self.header();
+        !matches!(header.frame_type, FrameType::UNKNOWN(_))
+            && header.flags != header.flags.sanitized_for(header.frame_type)
+    }
+
+    /// Returns the number of bytes this frame occupies on the wire, including the 9-byte header.
+    pub fn wire_len(&self) -> usize {
+        9 + self.header().length.length() as usize
+    }
+
+    /// Returns the Pad Length of a padded DATA or HEADERS frame as a share of its declared
+    /// payload length; other frames return `None`.
+    pub fn padding_ratio(&self) -> Option<f32> {
+        let pad_length = match self {
+            Self::Data(_, frame) => frame.pad_length,
+            Self::Headers(_, frame) => frame.pad_length,
+            _ => None,
+        }?;
+        Some(f32::from(pad_length) / self.header().length.length() as f32)
+    }
+
+    /// Whether the frame's [`padding_ratio`](Self::padding_ratio) exceeds
+    /// `options.max_padding_ratio`. Padding is legal at any size, so this only flags the frame,
+    /// e.g. for intrusion detection; parsing never rejects a frame for it.
+    pub fn is_over_padded(&self, options: &ParseOptions) -> bool {
+        options
+            .max_padding_ratio
+            .zip(self.padding_ratio())
+            .is_some_and(|(max_ratio, ratio)| ratio > max_ratio)
+    }
+}
+
+/// Renders a one-line summary such as `HEADERS stream=1 flags=END_STREAM|END_HEADERS len=42`,
+/// where `len` is the payload length computed from the parsed fields.
+impl core::fmt::Display for Frame<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let header = self.header();
+        write!(
+            f,
+            "{} stream={} flags={} len={}",
+            header.frame_type,
+            header.stream_identifier.stream_identifier(),
+            header.flags.display_for(header.frame_type),
+            self.payload_len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod frame_tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use crate::{
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{
+            ErrorCode, Frame, FrameHeader, FrameHeaderLength, FrameType, LengthReport, OriginEntry,
+            PingFrame, RstStreamFrame, SettingsFrame, SettingsParameter, SettingsParameterFrame,
+            StreamDependency, StreamIdentifier, UnknownFrameType, ValueKind, WindowSizeIncrement,
+        },
+        test_corpus::{
+            data_frame_bytes, frame_bytes, ping_frame_bytes, settings_ack_bytes,
+            settings_frame_bytes,
+        },
+    };
+
+    #[test]
+    fn test_headers_optional_fields() {
+        let payload = [0x02, 0x00, 0x00, 0x00, 0x01, 0x0F, 0x82, 0x00, 0x00];
+        let flags = Flags::PADDED | Flags::PRIORITY | Flags::END_HEADERS;
+        let bytes = frame_bytes(FrameType::HEADERS, flags, 3, &payload);
+        let (_, Frame::Headers(_, headers)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected a HEADERS frame");
+        };
+        assert!(headers.was_padded());
+        assert!(headers.has_priority());
+        assert_eq!(b"\x82", headers.header_block_fragment);
+
+        let bytes = frame_bytes(FrameType::HEADERS, Flags::END_HEADERS, 3, b"\x82");
+        let (_, Frame::Headers(_, headers)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected a HEADERS frame");
+        };
+        assert!(!headers.was_padded());
+        assert!(!headers.has_priority());
+    }
+
+    #[test]
+    fn test_empty_settings() {
+        let (empty_bytes, ack_bytes) = (settings_frame_bytes(&[]), settings_ack_bytes());
+        let (_, empty) = Frame::parse(&empty_bytes).unwrap();
+        let (_, ack) = Frame::parse(&ack_bytes).unwrap();
+        let (Frame::Settings(_, empty), Frame::Settings(_, ack)) = (empty, ack) else {
+            panic!("expected SETTINGS frames");
+        };
+
+        assert_eq!(Some(&[][..]), empty.parameters);
+        assert!(empty.is_empty_settings());
+        assert_eq!(None, ack.parameters);
+        assert!(!ack.is_empty_settings());
+    }
+
+    #[test]
+    fn test_settings_view() {
+        let parameters = [
+            (SettingsParameter::SETTINGS_HEADER_TABLE_SIZE, 0),
+            (SettingsParameter::SETTINGS_INITIAL_WINDOW_SIZE, 1 << 20),
+            (SettingsParameter::RESERVED(0x42), 7),
+        ];
+        let bytes = settings_frame_bytes(&parameters);
+        let length = FrameHeaderLength::new().with_length(18);
+        let (tail, view) = SettingsFrame::view(&bytes[9..], &length, &Flags::NONE).unwrap();
+
+        assert!(tail.is_empty());
+        assert_eq!(3, view.into_iter().len());
+        assert_eq!(parameters, view.into_iter().collect::<Vec<_>>()[..]);
+
+        let length = FrameHeaderLength::new().with_length(17);
+        assert!(SettingsFrame::view(&bytes[9..], &length, &Flags::NONE).is_err());
+    }
+
+    #[test]
+    fn test_settings_semantically_eq() {
+        let a = SettingsParameter::SETTINGS_ENABLE_PUSH;
+        let b = SettingsParameter::SETTINGS_MAX_FRAME_SIZE;
+        let frame = |parameters: &[(SettingsParameter, u32)]| {
+            parameters
+                .iter()
+                .map(|&(parameter, value)| SettingsParameterFrame::new(parameter, value))
+                .collect::<Vec<_>>()
+        };
+        let ab = frame(&[(a, 1), (b, 2)]);
+        let ba = frame(&[(b, 2), (a, 1)]);
+        let repeated = frame(&[(b, 9), (a, 1), (b, 2)]);
+        let changed = frame(&[(a, 0), (b, 2)]);
+        let settings = |parameters| SettingsFrame {
+            parameters: Some(parameters),
+        };
+
+        assert!(settings(&ab).semantically_eq(&settings(&ba)));
+        assert!(settings(&ab).semantically_eq(&settings(&repeated)));
+        assert!(!settings(&ab).semantically_eq(&settings(&changed)));
+        assert!(!settings(&ab).semantically_eq(&settings(&ab[..1])));
+    }
+
+    #[test]
+    fn test_frame_payload() {
+        let bytes = data_frame_bytes(1, b"hello", None);
+        let (_, data) = Frame::parse(&bytes).unwrap();
+        assert_eq!(Some(&b"hello"[..]), data.payload());
+
+        let bytes = ping_frame_bytes(42, false);
+        let (_, ping) = Frame::parse(&bytes).unwrap();
+        assert_eq!(None, ping.payload());
+    }
+
+    #[test]
+    fn test_length_check() {
+        let bytes = data_frame_bytes(1, b"hello", Some(3));
+        let (_, data) = Frame::parse(&bytes).unwrap();
+        let report = data.length_check();
+        assert_eq!(LengthReport { declared: 9, actual: 9 }, report);
+        assert!(!report.is_mismatch());
+
+        let Frame::Data(header, mut frame) = data else {
+            panic!("expected a DATA frame");
+        };
+        frame.padding = None;
+        assert!(Frame::Data(header, frame).length_check().is_mismatch());
+    }
+
+    #[test]
+    fn test_has_invalid_flag_bits() {
+        let bytes = frame_bytes(FrameType::DATA, Flags(0x40), 1, b"hello");
+        let (_, data) = Frame::parse(&bytes).unwrap();
+        assert!(data.has_invalid_flag_bits());
+
+        let bytes = frame_bytes(FrameType::DATA, Flags::END_STREAM, 1, b"hello");
+        let (_, data) = Frame::parse(&bytes).unwrap();
+        assert!(!data.has_invalid_flag_bits());
+    }
+
+    #[test]
+    fn test_rst_stream_reason() {
+        assert_eq!((8, "CANCEL"), RstStreamFrame::new(ErrorCode::from(0x8)).reason());
+        assert_eq!((256, "UNKNOWN"), RstStreamFrame::new(ErrorCode::from(0x100)).reason());
+    }
+
+    #[test]
+    fn test_error_code_all_known() {
+        let codes = ErrorCode::all_known();
+        assert_eq!(14, codes.len());
+        assert_eq!(ErrorCode::NO_ERROR, codes[0]);
+        assert_eq!(ErrorCode::HTTP_1_1_REQUIRED, codes[13]);
+        assert!(!codes.iter().any(|code| matches!(code, ErrorCode::UNKNOWN(_))));
+    }
+
+    #[test]
+    fn test_frame_type_try_from_u8() {
+        assert_eq!(Ok(FrameType::HEADERS), FrameType::try_from_u8(0x1));
+        assert_eq!(Err(UnknownFrameType(0xff)), FrameType::try_from_u8(0xff));
+        assert_eq!(
+            Http2ParseError::UnknownFrameType(0xff),
+            Http2ParseError::from(UnknownFrameType(0xff))
+        );
+    }
+
+    #[test]
+    fn test_bitfields_from_be_bytes() {
+        let length = FrameHeaderLength::from_be_bytes(&[0x01, 0x02, 0x03]);
+        assert_eq!(0x01_0203, length.length());
+
+        let stream_identifier = StreamIdentifier::from_be_bytes(&[0x80, 0x00, 0x01, 0x02]);
+        assert_eq!(0x0102, stream_identifier.stream_identifier());
+        assert_eq!(0x8000_0102, stream_identifier.into_bits());
+
+        let dependency = StreamDependency::from_be_bytes(&[0x80, 0x00, 0x00, 0x03]);
+        assert!(dependency.exclusive());
+        assert_eq!(3, dependency.stream_identifier());
+        let dependency = StreamDependency::from_be_bytes(&[0x00, 0x00, 0x01, 0x00]);
+        assert!(!dependency.exclusive());
+        assert_eq!(0x100, dependency.stream_identifier());
+
+        let increment = WindowSizeIncrement::from_be_bytes(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(0x7FFF_FFFF, increment.value());
+        let increment = WindowSizeIncrement::from_be_bytes(&[0x00, 0x01, 0x00, 0x00]);
+        assert_eq!(0x1_0000, increment.value());
+    }
+
+    #[test]
+    fn test_origin_entry_new() {
+        let entry = OriginEntry::new("https://example.com").unwrap();
+        assert_eq!(19, entry.origin_length);
+        assert_eq!(Some("https://example.com"), entry.ascii_origin);
+
+        let mut buf = Vec::new();
+        entry.write(&mut buf);
+        assert_eq!(b"\x00\x13https://example.com", buf.as_slice());
+
+        assert!(OriginEntry::new("https://ex\u{e4}mple.com").is_err());
+        assert!(OriginEntry::new("").is_err());
+        let long = "a".repeat(usize::from(u16::MAX) + 1);
+        assert_eq!(Err(Http2ParseError::FrameSize), OriginEntry::new(&long));
+    }
+
+    #[test]
+    fn test_window_size_increment_ignores_reserved_bit() {
+        let increment = WindowSizeIncrement::from_bits(0x8000_0001);
+        assert_eq!(1, increment.value());
+        assert_eq!(1i64, increment.as_i64());
+        assert_eq!(
+            0x7FFF_FFFF,
+            WindowSizeIncrement::from_bits(u32::MAX).as_i64()
+        );
+    }
+
+    #[test]
+    fn test_ping_opaque_bytes_round_trip() {
+        let opaque = *b"\xFFrtt\x00\x01\x02\x03";
+        let ping = PingFrame::from_bytes(opaque);
+        assert_eq!(opaque, ping.opaque_bytes());
+        assert_eq!(0xFF72_7474_0001_0203, ping.opaque_data);
+
+        let bytes = ping_frame_bytes(ping.opaque_data, false);
+        assert_eq!(opaque, bytes[9..]);
+        let (_, Frame::Ping(_, parsed)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected a PING frame");
+        };
+        assert_eq!(opaque, parsed.opaque_bytes());
+    }
+
+    #[test]
+    fn test_frame_display() {
+        let mut bytes = data_frame_bytes(1, b"hello", None);
+        bytes[4] = Flags::END_STREAM.bits();
+        let (_, frame) = Frame::parse(&bytes).unwrap();
+        assert_eq!("DATA stream=1 flags=END_STREAM len=5", frame.to_string());
+
+        let bytes = frame_bytes(FrameType::UNKNOWN(0x42), Flags::NONE, 0, b"xy");
+        let (_, frame) = Frame::parse(&bytes).unwrap();
+        assert_eq!("UNKNOWN(0x42) stream=0 flags=NONE len=2", frame.to_string());
+    }
+
+    #[test]
+    fn test_settings_value_kind_display() {
+        let enable_push = SettingsParameterFrame::new(SettingsParameter::SETTINGS_ENABLE_PUSH, 1);
+        assert_eq!(ValueKind::Boolean, enable_push.identifier().value_kind());
+        assert_eq!("SETTINGS_ENABLE_PUSH = true", enable_push.to_string());
+
+        let disable_push = SettingsParameterFrame::new(SettingsParameter::SETTINGS_ENABLE_PUSH, 0);
+        assert_eq!("SETTINGS_ENABLE_PUSH = false", disable_push.to_string());
+
+        let frame_size = SettingsParameterFrame::new(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16384);
+        assert_eq!(ValueKind::Bytes, frame_size.identifier().value_kind());
+        assert_eq!("SETTINGS_MAX_FRAME_SIZE = 16384", frame_size.to_string());
+    }
+
+    #[test]
+    fn test_frame_header_new() {
+        let header = FrameHeader::new(FrameType::DATA, Flags::END_STREAM, 0x8000_0003, 0xFF_FFFF);
+        let header = header.unwrap();
+        assert_eq!(FrameType::DATA, header.frame_type);
+        assert_eq!(Flags::END_STREAM, header.flags);
+        assert_eq!(3, header.stream_identifier.stream_identifier());
+        assert_eq!(3, header.stream_identifier.into_bits());
+        assert_eq!(0xFF_FFFF, header.length.length());
+
+        assert!(FrameHeader::new(FrameType::DATA, Flags::NONE, 1, 0x100_0000).is_none());
+    }
+
+    #[test]
+    fn test_settings_into_iter() {
+        let payload: [u8; 12] = [
+            0x00, 0x03, 0x00, 0x00, 0x00, 0x64, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xFF,
+        ];
+        let length = FrameHeaderLength::new().with_length(12);
+        let (_, settings) = SettingsFrame::parse(&payload, &length, &Flags::NONE).unwrap();
+
+        let mut iter = (&settings).into_iter();
+        assert_eq!(
+            Some((SettingsParameter::SETTINGS_MAX_CONCURRENT_STREAMS, 100)),
+            iter.next()
+        );
+        assert_eq!(
+            Some((SettingsParameter::SETTINGS_INITIAL_WINDOW_SIZE, 0xFFFF)),
+            iter.next()
+        );
+        assert_eq!(None, iter.next());
+
+        let ack = SettingsFrame { parameters: None };
+        assert_eq!(0, (&ack).into_iter().count());
+    }
 }
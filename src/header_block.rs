@@ -0,0 +1,241 @@
+use alloc::vec::Vec;
+
+use crate::{
+    error::Http2ParseError,
+    flags::Flags,
+    frames::{ErrorCode, Frame},
+    options::ParseOptions,
+};
+
+/// The default cap on CONTINUATION frames following a single HEADERS or PUSH_PROMISE.
+pub const DEFAULT_MAX_CONTINUATION_FRAMES: usize = 64;
+
+/// The default cap on the total size of one header block fragment sequence, in bytes.
+pub const DEFAULT_MAX_HEADER_BLOCK_SIZE: usize = 256 * 1024;
+
+/// A complete header block, reassembled from a HEADERS or PUSH_PROMISE frame and any
+/// CONTINUATION frames that followed it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderBlock {
+    pub stream_id: u32,
+    /// Set when the block came from PUSH_PROMISE.
+    pub promised_stream_id: Option<u32>,
+    /// The END_STREAM flag of the opening HEADERS frame.
+    pub end_stream: bool,
+    pub fragment: Vec<u8>,
+}
+
+/// Joins HEADERS/PUSH_PROMISE fragments with their CONTINUATION frames (RFC 7540 §6.10).
+///
+/// While a block is open, any frame other than a CONTINUATION on the same stream is a
+/// connection `PROTOCOL_ERROR` (RFC 9113 §6.10); a non-strict assembler instead lets frames on
+/// stream 0 through. The number of CONTINUATION frames and the total fragment size
+/// are capped so a peer cannot withhold END_HEADERS to exhaust memory; exceeding either cap is
+/// a connection `ENHANCE_YOUR_CALM`.
+#[derive(Debug)]
+pub struct HeaderBlockAssembler {
+    pending: Option<HeaderBlock>,
+    continuations: usize,
+    max_continuation_frames: usize,
+    max_header_block_size: usize,
+    strict: bool,
+}
+
+impl Default for HeaderBlockAssembler {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CONTINUATION_FRAMES, DEFAULT_MAX_HEADER_BLOCK_SIZE)
+    }
+}
+
+impl HeaderBlockAssembler {
+    pub fn new(max_continuation_frames: usize, max_header_block_size: usize) -> Self {
+        Self {
+            pending: None,
+            continuations: 0,
+            max_continuation_frames,
+            max_header_block_size,
+            strict: true,
+        }
+    }
+
+    /// Creates an assembler with the default caps that follows `options.rfc9113_strict`.
+    pub fn with_options(options: &ParseOptions) -> Self {
+        Self {
+            strict: options.rfc9113_strict,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a header block has been started but has not yet seen END_HEADERS.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Feeds one received frame, returning the header block once END_HEADERS completes it.
+    ///
+    /// Frames that neither start nor continue a header block are ignored while no block is open.
+    pub fn push(&mut self, frame: &Frame) -> Result<Option<HeaderBlock>, Http2ParseError> {
+        let header = frame.header();
+        let stream_id = header.stream_identifier.stream_identifier();
+        let end_headers = header.flags.contains(Flags::END_HEADERS);
+
+        let block = match (&mut self.pending, frame) {
+            (None, Frame::Headers(_, headers)) => HeaderBlock {
+                stream_id,
+                promised_stream_id: None,
+                end_stream: header.flags.contains(Flags::END_STREAM),
+                fragment: headers.header_block_fragment.to_vec(),
+            },
+            (None, Frame::PushPromise(_, push_promise)) => HeaderBlock {
+                stream_id,
+                promised_stream_id: Some(
+                    push_promise
+                        .promised_stream_identifier
+                        .stream_identifier(),
+                ),
+                end_stream: false,
+                fragment: push_promise.header_block_fragment.to_vec(),
+            },
+            (None, Frame::Continuation(..)) => {
+                return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+            }
+            (None, _) => return Ok(None),
+            (Some(pending), Frame::Continuation(_, continuation))
+                if pending.stream_id == stream_id =>
+            {
+                self.continuations += 1;
+                if self.continuations > self.max_continuation_frames
+                    || pending.fragment.len() + continuation.header_block_fragment.len()
+                        > self.max_header_block_size
+                {
+                    self.reset();
+                    return Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM));
+                }
+                pending
+                    .fragment
+                    .extend_from_slice(continuation.header_block_fragment);
+                if !end_headers {
+                    return Ok(None);
+                }
+                let block = self.pending.take();
+                self.continuations = 0;
+                return Ok(block);
+            }
+            (Some(_), _) if !self.strict && stream_id == 0 => return Ok(None),
+            (Some(_), _) => {
+                self.reset();
+                return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+            }
+        };
+
+        if block.fragment.len() > self.max_header_block_size {
+            return Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM));
+        }
+        if end_headers {
+            return Ok(Some(block));
+        }
+        self.pending = Some(block);
+        Ok(None)
+    }
+
+    /// Discards any partially assembled block.
+    pub fn reset(&mut self) {
+        self.pending = None;
+        self.continuations = 0;
+    }
+}
+
+#[cfg(test)]
+mod header_block_tests {
+    use crate::{
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{ErrorCode, Frame, FrameType},
+        options::ParseOptions,
+        test_corpus::{
+            continuation_frame_bytes, frame_bytes, headers_frame_bytes, settings_ack_bytes,
+        },
+    };
+
+    use super::HeaderBlockAssembler;
+
+    fn push(assembler: &mut HeaderBlockAssembler, bytes: &[u8]) -> Result<bool, Http2ParseError> {
+        let (_, frame) = Frame::parse(bytes).unwrap();
+        assembler.push(&frame).map(|block| block.is_some())
+    }
+
+    #[test]
+    fn test_headers_with_continuations() {
+        let mut assembler = HeaderBlockAssembler::default();
+
+        let opening = frame_bytes(FrameType::HEADERS, Flags::END_STREAM, 1, b"\x82");
+        assert_eq!(Ok(false), push(&mut assembler, &opening));
+        assert_eq!(Ok(false), push(&mut assembler, &continuation_frame_bytes(1, b"\x84", false)));
+
+        let bytes = continuation_frame_bytes(1, b"\x86", true);
+        let (_, last) = Frame::parse(&bytes).unwrap();
+        let block = assembler.push(&last).unwrap().unwrap();
+        assert_eq!(1, block.stream_id);
+        assert!(block.end_stream);
+        assert_eq!(b"\x82\x84\x86".to_vec(), block.fragment);
+        assert!(!assembler.is_pending());
+
+        let complete = headers_frame_bytes(3, Flags::NONE, b"\x82", None);
+        assert_eq!(Ok(true), push(&mut assembler, &complete));
+    }
+
+    #[test]
+    fn test_interleaved_frame_is_protocol_error() {
+        let mut assembler = HeaderBlockAssembler::default();
+        let protocol_error = Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+
+        let opening = frame_bytes(FrameType::HEADERS, Flags::NONE, 1, b"\x82");
+        assert_eq!(Ok(false), push(&mut assembler, &opening));
+        assert_eq!(
+            protocol_error,
+            push(&mut assembler, &continuation_frame_bytes(3, b"\x84", true))
+        );
+        assert_eq!(
+            protocol_error,
+            push(&mut assembler, &continuation_frame_bytes(1, b"\x84", true))
+        );
+    }
+
+    #[test]
+    fn test_rfc9113_strict_interleaving() {
+        let opening = frame_bytes(FrameType::HEADERS, Flags::NONE, 1, b"\x82");
+        let settings = settings_ack_bytes();
+        let last = continuation_frame_bytes(1, b"\x84", true);
+
+        let mut strict = HeaderBlockAssembler::with_options(&ParseOptions::endpoint());
+        assert_eq!(Ok(false), push(&mut strict, &opening));
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            push(&mut strict, &settings)
+        );
+        assert!(!strict.is_pending());
+
+        let mut lenient = HeaderBlockAssembler::with_options(&ParseOptions::inspector());
+        assert_eq!(Ok(false), push(&mut lenient, &opening));
+        assert_eq!(Ok(false), push(&mut lenient, &settings));
+        assert_eq!(Ok(true), push(&mut lenient, &last));
+    }
+
+    #[test]
+    fn test_continuation_flood() {
+        let mut assembler = HeaderBlockAssembler::new(100, usize::MAX);
+        let opening = frame_bytes(FrameType::HEADERS, Flags::NONE, 1, b"\x82");
+        assert_eq!(Ok(false), push(&mut assembler, &opening));
+
+        let continuation = continuation_frame_bytes(1, b"\x84", false);
+        let result = (0..10_000)
+            .map(|_| push(&mut assembler, &continuation))
+            .find(Result::is_err);
+
+        assert_eq!(
+            Some(Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM))),
+            result
+        );
+        assert!(!assembler.is_pending());
+    }
+}
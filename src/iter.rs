@@ -0,0 +1,162 @@
+use crate::{
+    error::Http2ParseError,
+    frames::{ErrorCode, Frame, FrameHeader},
+    options::ParseOptions,
+};
+
+/// Parses consecutive frames out of a buffer, yielding each one in turn.
+///
+/// Iteration stops after the first error, including a truncated trailing frame.
+#[derive(Debug, Clone)]
+pub struct FrameIter<'a> {
+    remaining: &'a [u8],
+    options: ParseOptions,
+    max_frames: Option<usize>,
+    yielded: usize,
+}
+
+impl<'a> FrameIter<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_options(bytes, ParseOptions::default())
+    }
+
+    pub fn with_options(bytes: &'a [u8], options: ParseOptions) -> Self {
+        Self {
+            remaining: bytes,
+            options,
+            max_frames: None,
+            yielded: 0,
+        }
+    }
+
+    /// Caps the number of frames yielded; the next one fails with `ENHANCE_YOUR_CALM`, so a
+    /// flood of empty frames cannot keep the caller busy indefinitely.
+    pub fn max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    /// The bytes not yet consumed.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.remaining
+    }
+}
+
+impl<'a> Iterator for FrameIter<'a> {
+    type Item = Result<Frame<'a>, Http2ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        if self.max_frames.is_some_and(|max| self.yielded >= max) {
+            self.remaining = &[];
+            return Some(Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM)));
+        }
+
+        match Frame::parse_with_options(self.remaining, &self.options) {
+            Ok((tail, frame)) => {
+                // Every frame carries a 9-byte header, so even an empty one moves the cursor.
+                debug_assert!(self.remaining.len() - tail.len() >= 9);
+                self.remaining = tail;
+                self.yielded += 1;
+                Some(Ok(frame))
+            }
+            Err(err) => {
+                self.remaining = &[];
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Yields the headers of consecutive frames, skipping each payload without decoding it.
+///
+/// Iteration ends before a truncated final frame, which is left in [`Self::remaining`] so the
+/// caller can complete it once more bytes arrive.
+#[derive(Debug, Clone)]
+pub struct FrameHeaderIter<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> FrameHeaderIter<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { remaining: bytes }
+    }
+
+    /// The bytes not yet consumed.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.remaining
+    }
+}
+
+impl Iterator for FrameHeaderIter<'_> {
+    type Item = FrameHeader;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (payload, header) = FrameHeader::parse(self.remaining).ok()?;
+        let payload_len = header.length.length() as usize;
+        let tail = payload.get(payload_len..)?;
+        self.remaining = tail;
+        Some(header)
+    }
+}
+
+#[cfg(test)]
+mod iter_tests {
+    use alloc::vec::Vec;
+
+    use crate::{
+        error::Http2ParseError,
+        frames::{ErrorCode, FrameType},
+        test_corpus::{data_frame_bytes, ping_frame_bytes, settings_ack_bytes},
+    };
+
+    use super::{FrameHeaderIter, FrameIter};
+
+    #[test]
+    fn test_header_iter_skips_payloads() {
+        let frames = [
+            data_frame_bytes(1, b"hello", None),
+            settings_ack_bytes(),
+            ping_frame_bytes(7, false),
+        ];
+        let mut bytes = frames.concat();
+
+        let types: Vec<_> = FrameHeaderIter::new(&bytes)
+            .map(|header| header.frame_type)
+            .collect();
+        assert_eq!([FrameType::DATA, FrameType::SETTINGS, FrameType::PING], types[..]);
+
+        bytes.extend_from_slice(&data_frame_bytes(3, b"truncated", None)[..12]);
+        let mut headers = FrameHeaderIter::new(&bytes);
+        assert_eq!(3, headers.by_ref().count());
+        assert_eq!(12, headers.remaining().len());
+    }
+
+    #[test]
+    fn test_zero_length_frames_terminate() {
+        let bytes: Vec<u8> = (0..1000).flat_map(|_| settings_ack_bytes()).collect();
+
+        let frames: Vec<_> = FrameIter::new(&bytes).collect();
+        assert_eq!(1000, frames.len());
+        assert!(frames.iter().all(|frame| {
+            frame
+                .as_ref()
+                .is_ok_and(|frame| frame.header().frame_type == FrameType::SETTINGS)
+        }));
+
+        let mut capped = FrameIter::new(&bytes).max_frames(100);
+        assert_eq!(100, capped.by_ref().take_while(Result::is_ok).count());
+        assert_eq!(None, capped.next());
+
+        let mut capped = FrameIter::new(&bytes).max_frames(100);
+        assert_eq!(
+            Some(Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM))),
+            capped.nth(100)
+        );
+        assert!(capped.remaining().is_empty());
+        assert_eq!(None, capped.next());
+    }
+}
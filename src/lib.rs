@@ -1,8 +1,33 @@
 #![no_std]
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod connection;
+#[cfg(feature = "std")]
+pub mod connection_writer;
+pub mod dissect;
+pub mod error;
 pub mod flags;
+#[cfg(feature = "std")]
+pub mod frame_reader;
 pub mod frames;
+pub mod header_block;
+pub mod iter;
+pub mod options;
+pub mod owned;
 pub mod parsers;
+pub mod priority;
+pub mod registry;
+#[cfg(feature = "serde")]
+mod serde_hex;
+pub mod settings;
+pub mod stats;
+#[cfg(test)]
+mod test_corpus;
+pub mod validate;
+pub mod writers;
 
 #[cfg(test)]
 mod tests {}
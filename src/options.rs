@@ -0,0 +1,82 @@
+/// Knobs controlling how strictly `Frame::parse_with_options` treats input that the RFC
+/// permits receivers to tolerate.
+///
+/// The default is lenient: anything the RFC says to ignore is ignored.
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    /// Return [`Http2ParseError::UnknownFrameType`](crate::error::Http2ParseError) instead of
+    /// producing `Frame::Unknown` for unrecognized frame types.
+    pub error_on_unknown_frame_type: bool,
+    /// The most parameters a single SETTINGS frame may carry before it is rejected with
+    /// `ENHANCE_YOUR_CALM`, bounding the work a peer can force per frame.
+    pub max_settings_params: usize,
+    /// Reject frames whose stream identifier does not suit their type, such as DATA on
+    /// stream 0 or SETTINGS on a nonzero stream, with a connection `PROTOCOL_ERROR`.
+    pub enforce_stream_identifiers: bool,
+    /// Reject a SETTINGS payload that is not a whole number of 6-octet parameters with
+    /// [`Http2ParseError::FrameSize`](crate::error::Http2ParseError). When unset, the
+    /// trailing partial parameter is skipped along with the rest of the declared payload.
+    pub enforce_settings_length: bool,
+    /// Treat any frame between a header block's opening frame and its final CONTINUATION as
+    /// a connection `PROTOCOL_ERROR`, including frames on stream 0 (RFC 9113 §6.10). When
+    /// unset, a [`HeaderBlockAssembler`](crate::header_block::HeaderBlockAssembler) lets
+    /// connection-level frames through while a block is open.
+    pub rfc9113_strict: bool,
+    /// The largest share of a DATA or HEADERS frame's payload that padding may take, from 0.0
+    /// to 1.0, measured as the Pad Length against the payload length declared in the frame
+    /// header (the 9-octet header itself not counted).
+    ///
+    /// Padding is legal at any size, so this is advisory: over-padded frames still parse, and
+    /// [`Frame::is_over_padded`](crate::frames::Frame::is_over_padded) flags them for
+    /// intrusion detection. `None` flags nothing.
+    pub max_padding_ratio: Option<f32>,
+    /// The longest header block fragment a single HEADERS, PUSH_PROMISE or CONTINUATION frame
+    /// may carry before it is rejected with a connection `PROTOCOL_ERROR`, checked before any
+    /// HPACK decoding. The assembler's `max_header_block_size` bounds the whole block; this
+    /// bounds each frame. `None` disables it.
+    pub max_header_fragment_len: Option<usize>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            error_on_unknown_frame_type: false,
+            max_settings_params: 64,
+            enforce_stream_identifiers: false,
+            enforce_settings_length: true,
+            rfc9113_strict: false,
+            max_padding_ratio: None,
+            max_header_fragment_len: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Options for passively inspecting traffic: decode whatever is structurally readable,
+    /// without stream identifier enforcement, a cap on SETTINGS parameters or their payload
+    /// length, or strict header block sequencing.
+    ///
+    /// Reserved bits are ignored and unknown frame types are surfaced as `Frame::Unknown`
+    /// under every preset, as RFC 7540 requires of receivers.
+    pub fn inspector() -> Self {
+        Self {
+            error_on_unknown_frame_type: false,
+            max_settings_params: usize::MAX,
+            enforce_stream_identifiers: false,
+            enforce_settings_length: false,
+            rfc9113_strict: false,
+            max_padding_ratio: None,
+            max_header_fragment_len: None,
+        }
+    }
+
+    /// Options for an endpoint that must hold its peer to RFC 7540, with header blocks
+    /// sequenced as RFC 9113 §6.10 requires.
+    pub fn endpoint() -> Self {
+        Self {
+            enforce_stream_identifiers: true,
+            rfc9113_strict: true,
+            ..Self::default()
+        }
+    }
+}
@@ -0,0 +1,680 @@
+use alloc::vec::Vec;
+
+use crate::{
+    error::Http2ParseError,
+    flags::Flags,
+    frames::{
+        AltSvcFrame, ContinuationFrame, DataFrame, ErrorCode, Frame, FrameHeader,
+        FrameType, GoAwayFrame, HeadersFrame,
+        OriginFrame, PingFrame, PriorityFrame, PushPromiseFrame, RstStreamFrame, SettingsFrame,
+        SettingsParameter, SettingsParameterFrame, StreamDependency, StreamIdentifier,
+        WindowUpdateFrame,
+    },
+    parsers::frame_total_len,
+    settings::Http2Settings,
+};
+
+/// An owned HTTP/2 DATA frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedDataFrame {
+    pub pad_length: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub data: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
+    pub padding: Option<Vec<u8>>,
+}
+
+/// An owned HTTP/2 HEADERS frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedHeadersFrame {
+    pub pad_length: Option<u8>,
+    pub stream_dependency: Option<StreamDependency>,
+    pub weight: Option<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub header_block_fragment: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
+    pub padding: Option<Vec<u8>>,
+}
+
+/// An owned HTTP/2 SETTINGS frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedSettingsFrame {
+    pub parameters: Option<Vec<SettingsParameterFrame>>,
+}
+
+impl OwnedSettingsFrame {
+    /// Wraps these parameters in a non-ACK SETTINGS frame on stream 0, checking each value
+    /// against its allowed range (RFC 7540 §6.5.2) and sizing the header to match.
+    pub fn into_frame(self) -> Result<OwnedFrame, Http2ParseError> {
+        let parameters = self.parameters.as_deref().unwrap_or_default();
+        let mut settings = Http2Settings::default();
+        for parameter in parameters {
+            settings.apply(parameter.identifier(), parameter.value())?;
+        }
+
+        let length = u32::try_from(parameters.len() * 6).map_err(|_| Http2ParseError::FrameSize)?;
+        let header = FrameHeader::new(FrameType::SETTINGS, Flags::NONE, 0, length)
+            .ok_or(Http2ParseError::FrameSize)?;
+        Ok(OwnedFrame::Settings(header, self))
+    }
+
+    /// Wraps this frame as a SETTINGS ACK on stream 0. An ACK carries no parameters and has a
+    /// length of zero, so any `parameters`, even an empty list, fail with
+    /// [`Http2ParseError::FrameSize`] (RFC 7540 §6.5).
+    pub fn into_ack_frame(self) -> Result<OwnedFrame, Http2ParseError> {
+        if self.parameters.is_some() {
+            return Err(Http2ParseError::FrameSize);
+        }
+        let header = FrameHeader::new(FrameType::SETTINGS, Flags::ACK, 0, 0)
+            .ok_or(Http2ParseError::FrameSize)?;
+        Ok(OwnedFrame::Settings(header, self))
+    }
+}
+
+impl FromIterator<(SettingsParameter, u32)> for OwnedSettingsFrame {
+    fn from_iter<I: IntoIterator<Item = (SettingsParameter, u32)>>(iter: I) -> Self {
+        let parameters = iter
+            .into_iter()
+            .map(|(parameter, value)| SettingsParameterFrame::new(parameter, value))
+            .collect();
+        Self {
+            parameters: Some(parameters),
+        }
+    }
+}
+
+/// An owned HTTP/2 PUSH_PROMISE frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedPushPromiseFrame {
+    pub pad_length: Option<u8>,
+    pub promised_stream_identifier: StreamIdentifier,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub header_block_fragment: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
+    pub padding: Option<Vec<u8>>,
+}
+
+/// An owned HTTP/2 GOAWAY frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedGoAwayFrame {
+    pub last_stream_identifier: StreamIdentifier,
+    pub error_code: ErrorCode,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex::option"))]
+    pub debug_data: Option<Vec<u8>>,
+}
+
+/// An owned HTTP/2 CONTINUATION frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedContinuationFrame {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub header_block_fragment: Vec<u8>,
+}
+
+/// An owned HTTP/2 ORIGIN frame, holding its raw payload of origin entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedOriginFrame {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub payload: Vec<u8>,
+}
+
+/// An owned HTTP/2 ALTSVC frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedAltSvcFrame {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub origin: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+    pub field_value: Vec<u8>,
+}
+
+/// An owned counterpart of [`Frame`] that does not borrow from the input buffer.
+///
+/// Frames without byte payloads reuse the borrowed structures directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OwnedFrame {
+    Data(FrameHeader, OwnedDataFrame),
+    Headers(FrameHeader, OwnedHeadersFrame),
+    Priority(FrameHeader, PriorityFrame),
+    RstStream(FrameHeader, RstStreamFrame),
+    Settings(FrameHeader, OwnedSettingsFrame),
+    PushPromise(FrameHeader, OwnedPushPromiseFrame),
+    Ping(FrameHeader, PingFrame),
+    GoAway(FrameHeader, OwnedGoAwayFrame),
+    WindowUpdate(FrameHeader, WindowUpdateFrame),
+    Continuation(FrameHeader, OwnedContinuationFrame),
+    AltSvc(FrameHeader, OwnedAltSvcFrame),
+    Origin(FrameHeader, OwnedOriginFrame),
+    Ignored(
+        FrameHeader,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+        Vec<u8>,
+    ),
+    Unknown(
+        FrameHeader,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_hex"))]
+        Vec<u8>,
+    ),
+}
+
+/// A frame that either borrows from its input buffer or owns its payloads, so a reader can
+/// hand out borrowed frames while its buffer is stable and detach them before refilling it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CowFrame<'a> {
+    Borrowed(Frame<'a>),
+    Owned(OwnedFrame),
+}
+
+impl<'a> Frame<'a> {
+    /// Wraps this frame as a borrowed [`CowFrame`] without copying.
+    pub fn into_cow(self) -> CowFrame<'a> {
+        CowFrame::Borrowed(self)
+    }
+}
+
+impl CowFrame<'_> {
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, Self::Borrowed(_))
+    }
+
+    /// Detaches the frame from its input buffer, copying the payloads if they are borrowed.
+    pub fn into_owned(self) -> CowFrame<'static> {
+        match self {
+            Self::Borrowed(frame) => CowFrame::Owned(frame.to_owned()),
+            Self::Owned(frame) => CowFrame::Owned(frame),
+        }
+    }
+
+    /// Views the frame as a [`Frame`], whichever way it is held.
+    pub fn as_frame(&self) -> Frame<'_> {
+        match self {
+            Self::Borrowed(frame) => *frame,
+            Self::Owned(frame) => frame.as_ref(),
+        }
+    }
+}
+
+impl Frame<'_> {
+    /// Copies every borrowed payload slice into an [`OwnedFrame`], keeping the header as-is:
+    /// the flags byte, declared length, and reserved stream identifier bit are not recomputed.
+    pub fn to_owned(&self) -> OwnedFrame {
+        match *self {
+            Frame::Data(header, frame) => OwnedFrame::Data(
+                header,
+                OwnedDataFrame {
+                    pad_length: frame.pad_length,
+                    data: frame.data.to_vec(),
+                    padding: frame.padding.map(<[u8]>::to_vec),
+                },
+            ),
+            Frame::Headers(header, frame) => OwnedFrame::Headers(
+                header,
+                OwnedHeadersFrame {
+                    pad_length: frame.pad_length,
+                    stream_dependency: frame.stream_dependency,
+                    weight: frame.weight,
+                    header_block_fragment: frame.header_block_fragment.to_vec(),
+                    padding: frame.padding.map(<[u8]>::to_vec),
+                },
+            ),
+            Frame::Priority(header, frame) => OwnedFrame::Priority(header, frame),
+            Frame::RstStream(header, frame) => OwnedFrame::RstStream(header, frame),
+            Frame::Settings(header, frame) => OwnedFrame::Settings(
+                header,
+                OwnedSettingsFrame {
+                    parameters: frame.parameters.map(<[SettingsParameterFrame]>::to_vec),
+                },
+            ),
+            Frame::PushPromise(header, frame) => OwnedFrame::PushPromise(
+                header,
+                OwnedPushPromiseFrame {
+                    pad_length: frame.pad_length,
+                    promised_stream_identifier: frame.promised_stream_identifier,
+                    header_block_fragment: frame.header_block_fragment.to_vec(),
+                    padding: frame.padding.map(<[u8]>::to_vec),
+                },
+            ),
+            Frame::Ping(header, frame) => OwnedFrame::Ping(header, frame),
+            Frame::GoAway(header, frame) => OwnedFrame::GoAway(
+                header,
+                OwnedGoAwayFrame {
+                    last_stream_identifier: frame.last_stream_identifier,
+                    error_code: frame.error_code,
+                    debug_data: frame.debug_data.map(<[u8]>::to_vec),
+                },
+            ),
+            Frame::WindowUpdate(header, frame) => OwnedFrame::WindowUpdate(header, frame),
+            Frame::Continuation(header, frame) => OwnedFrame::Continuation(
+                header,
+                OwnedContinuationFrame {
+                    header_block_fragment: frame.header_block_fragment.to_vec(),
+                },
+            ),
+            Frame::AltSvc(header, frame) => OwnedFrame::AltSvc(
+                header,
+                OwnedAltSvcFrame {
+                    origin: frame.origin.to_vec(),
+                    field_value: frame.field_value.to_vec(),
+                },
+            ),
+            Frame::Origin(header, frame) => OwnedFrame::Origin(
+                header,
+                OwnedOriginFrame {
+                    payload: frame.payload.to_vec(),
+                },
+            ),
+            Frame::Ignored(header, payload) => OwnedFrame::Ignored(header, payload.to_vec()),
+            Frame::Unknown(header, payload) => OwnedFrame::Unknown(header, payload.to_vec()),
+        }
+    }
+
+    /// Rebuilds this frame with `flags`, adding or dropping the payload fields they govern.
+    ///
+    /// Clearing PADDED removes the pad length octet and padding; setting it adds zero padding.
+    /// On HEADERS, clearing PRIORITY removes the dependency and weight, and setting it adds the
+    /// default priority (stream 0, weight 16). The header length is recomputed to match, and
+    /// fails with [`Http2ParseError::FrameSize`] if the added fields no longer fit in 24 bits.
+    pub fn with_flags(&self, flags: Flags) -> Result<OwnedFrame, Http2ParseError> {
+        let mut owned = self.to_owned();
+        let padded = flags.contains(Flags::PADDED);
+
+        let set_padding = |pad_length: &mut Option<u8>, padding: &mut Option<Vec<u8>>| {
+            if !padded {
+                *pad_length = None;
+                *padding = None;
+            } else if pad_length.is_none() {
+                *pad_length = Some(0);
+                *padding = Some(Vec::new());
+            }
+        };
+
+        match &mut owned {
+            OwnedFrame::Data(_, frame) => set_padding(&mut frame.pad_length, &mut frame.padding),
+            OwnedFrame::Headers(_, frame) => {
+                set_padding(&mut frame.pad_length, &mut frame.padding);
+                if !flags.contains(Flags::PRIORITY) {
+                    frame.stream_dependency = None;
+                    frame.weight = None;
+                } else if frame.stream_dependency.is_none() {
+                    frame.stream_dependency = Some(StreamDependency::new());
+                    frame.weight = Some(15);
+                }
+            }
+            OwnedFrame::PushPromise(_, frame) => {
+                set_padding(&mut frame.pad_length, &mut frame.padding);
+            }
+            _ => {}
+        }
+
+        let length =
+            u32::try_from(owned.as_ref().payload_len()).map_err(|_| Http2ParseError::FrameSize)?;
+        let header = owned.header_mut();
+        *header = FrameHeader {
+            stream_identifier: header.stream_identifier,
+            ..FrameHeader::new(header.frame_type, flags, 0, length)
+                .ok_or(Http2ParseError::FrameSize)?
+        };
+        Ok(owned)
+    }
+
+    /// Parses one frame from input split across two segments, such as the two halves of a
+    /// wrapped ring buffer, as if `second` followed `first`. Returns the number of bytes
+    /// consumed across both segments.
+    ///
+    /// Only a frame that straddles the boundary is copied into a contiguous buffer first.
+    pub fn parse_segmented(
+        first: &[u8],
+        second: &[u8],
+    ) -> Result<(usize, OwnedFrame), Http2ParseError> {
+        if first.is_empty() {
+            return OwnedFrame::parse(second);
+        }
+
+        let available = first.len() + second.len();
+        if available < 9 {
+            return Err(Http2ParseError::Incomplete {
+                needed: 9 - available,
+            });
+        }
+        let mut header = [0; 9];
+        for (dst, src) in header.iter_mut().zip(first.iter().chain(second)) {
+            *dst = *src;
+        }
+        let total_len = frame_total_len(&header);
+        if total_len <= first.len() {
+            return OwnedFrame::parse(first);
+        }
+        if total_len > available {
+            return Err(Http2ParseError::Incomplete {
+                needed: total_len - available,
+            });
+        }
+
+        let mut bytes = Vec::with_capacity(total_len);
+        bytes.extend_from_slice(first);
+        bytes.extend_from_slice(&second[..total_len - first.len()]);
+        OwnedFrame::parse(&bytes)
+    }
+}
+
+impl OwnedFrame {
+    /// Parses one frame and copies it out of `bytes`, returning the number of bytes consumed.
+    pub fn parse(bytes: &[u8]) -> Result<(usize, OwnedFrame), Http2ParseError> {
+        let (tail, frame) = Frame::parse(bytes)?;
+        Ok((bytes.len() - tail.len(), frame.to_owned()))
+    }
+
+    /// Returns the frame header shared by every variant.
+    pub fn header(&self) -> &FrameHeader {
+        match self {
+            Self::Data(header, _)
+            | Self::Headers(header, _)
+            | Self::Priority(header, _)
+            | Self::RstStream(header, _)
+            | Self::Settings(header, _)
+            | Self::PushPromise(header, _)
+            | Self::Ping(header, _)
+            | Self::GoAway(header, _)
+            | Self::WindowUpdate(header, _)
+            | Self::Continuation(header, _)
+            | Self::AltSvc(header, _)
+            | Self::Origin(header, _)
+            | Self::Ignored(header, _)
+            | Self::Unknown(header, _) => header,
+        }
+    }
+
+    fn header_mut(&mut self) -> &mut FrameHeader {
+        match self {
+            Self::Data(header, _)
+            | Self::Headers(header, _)
+            | Self::Priority(header, _)
+            | Self::RstStream(header, _)
+            | Self::Settings(header, _)
+            | Self::PushPromise(header, _)
+            | Self::Ping(header, _)
+            | Self::GoAway(header, _)
+            | Self::WindowUpdate(header, _)
+            | Self::Continuation(header, _)
+            | Self::AltSvc(header, _)
+            | Self::Origin(header, _)
+            | Self::Ignored(header, _)
+            | Self::Unknown(header, _) => header,
+        }
+    }
+
+    /// Borrows this frame as a [`Frame`] so it can be validated or written like a parsed one.
+    pub fn as_ref(&self) -> Frame<'_> {
+        match self {
+            Self::Data(header, frame) => Frame::Data(
+                *header,
+                DataFrame {
+                    pad_length: frame.pad_length,
+                    data: &frame.data,
+                    padding: frame.padding.as_deref(),
+                },
+            ),
+            Self::Headers(header, frame) => Frame::Headers(
+                *header,
+                HeadersFrame {
+                    pad_length: frame.pad_length,
+                    stream_dependency: frame.stream_dependency,
+                    weight: frame.weight,
+                    header_block_fragment: &frame.header_block_fragment,
+                    padding: frame.padding.as_deref(),
+                },
+            ),
+            Self::Priority(header, frame) => Frame::Priority(*header, *frame),
+            Self::RstStream(header, frame) => Frame::RstStream(*header, *frame),
+            Self::Settings(header, frame) => Frame::Settings(
+                *header,
+                SettingsFrame {
+                    parameters: frame.parameters.as_deref(),
+                },
+            ),
+            Self::PushPromise(header, frame) => Frame::PushPromise(
+                *header,
+                PushPromiseFrame {
+                    pad_length: frame.pad_length,
+                    promised_stream_identifier: frame.promised_stream_identifier,
+                    header_block_fragment: &frame.header_block_fragment,
+                    padding: frame.padding.as_deref(),
+                },
+            ),
+            Self::Ping(header, frame) => Frame::Ping(*header, *frame),
+            Self::GoAway(header, frame) => Frame::GoAway(
+                *header,
+                GoAwayFrame {
+                    last_stream_identifier: frame.last_stream_identifier,
+                    error_code: frame.error_code,
+                    debug_data: frame.debug_data.as_deref(),
+                },
+            ),
+            Self::WindowUpdate(header, frame) => Frame::WindowUpdate(*header, *frame),
+            Self::Continuation(header, frame) => Frame::Continuation(
+                *header,
+                ContinuationFrame {
+                    header_block_fragment: &frame.header_block_fragment,
+                },
+            ),
+            Self::AltSvc(header, frame) => Frame::AltSvc(
+                *header,
+                AltSvcFrame {
+                    origin: &frame.origin,
+                    field_value: &frame.field_value,
+                },
+            ),
+            Self::Origin(header, frame) => Frame::Origin(
+                *header,
+                OriginFrame {
+                    payload: &frame.payload,
+                },
+            ),
+            Self::Ignored(header, payload) => Frame::Ignored(*header, payload),
+            Self::Unknown(header, payload) => Frame::Unknown(*header, payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod owned_tests {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{
+        flags::Flags,
+        error::Http2ParseError,
+        frames::{
+            DataFrame, ErrorCode, Frame, FrameHeader, FrameType, HeadersFrame, SettingsParameter,
+        },
+        test_corpus::{data_frame_bytes, frame_bytes, ping_frame_bytes},
+    };
+
+    use super::{CowFrame, OwnedFrame, OwnedSettingsFrame};
+
+    #[test]
+    fn test_to_owned_keeps_header_verbatim() {
+        let mut bytes = frame_bytes(FrameType::DATA, Flags(0x41), 1, b"hello");
+        bytes[5] |= 0x80;
+        let (_, frame) = Frame::parse(&bytes).unwrap();
+
+        let owned = frame.to_owned();
+        assert_eq!(frame.header(), owned.header());
+        assert_eq!(frame, owned.as_ref());
+        assert_eq!(Flags(0x41), owned.header().flags);
+        assert_eq!(0x8000_0001, owned.header().stream_identifier.into_bits());
+    }
+
+    #[test]
+    fn test_parse_segmented() {
+        let mut bytes = ping_frame_bytes(42, false);
+        bytes.extend(data_frame_bytes(1, b"hello", None));
+        let (first, second) = bytes.split_at(12);
+
+        let (consumed, ping) = Frame::parse_segmented(first, second).unwrap();
+        assert_eq!(17, consumed);
+        assert_eq!(Frame::parse(&bytes).unwrap().1.to_owned(), ping);
+
+        let (consumed, data) = Frame::parse_segmented(&[], &bytes[17..]).unwrap();
+        assert_eq!(14, consumed);
+        assert_eq!(Some(&b"hello"[..]), data.as_ref().payload());
+
+        assert_eq!(
+            Err(Http2ParseError::Incomplete { needed: 5 }),
+            Frame::parse_segmented(&bytes[..4], &bytes[4..12])
+        );
+    }
+
+    #[test]
+    fn test_settings_from_iter() {
+        let settings: OwnedSettingsFrame = [
+            (SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16_384),
+            (SettingsParameter::SETTINGS_ENABLE_PUSH, 0),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(Some(2), settings.parameters.as_ref().map(Vec::len));
+
+        let frame = settings.into_frame().unwrap();
+        assert_eq!(12, frame.header().length.length());
+        assert_eq!(12, frame.as_ref().payload_len());
+
+        let invalid: OwnedSettingsFrame = [(SettingsParameter::SETTINGS_ENABLE_PUSH, 2)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            invalid.into_frame()
+        );
+    }
+
+    #[test]
+    fn test_owned_parse_data() {
+        let mut bytes = data_frame_bytes(1, b"hello", None);
+        let wire_len = bytes.len();
+        bytes.extend_from_slice(b"trailing");
+
+        let (consumed, frame) = OwnedFrame::parse(&bytes).unwrap();
+        drop(bytes);
+
+        assert_eq!(wire_len, consumed);
+        let OwnedFrame::Data(header, data) = frame else {
+            panic!("expected a DATA frame");
+        };
+        assert_eq!(1, header.stream_identifier.stream_identifier());
+        assert_eq!(b"hello".to_vec(), data.data);
+        assert_eq!(None, data.padding);
+    }
+
+    #[test]
+    fn test_with_flags_strips_padding() {
+        let frame = Frame::Data(
+            FrameHeader::new(FrameType::DATA, Flags::PADDED | Flags::END_STREAM, 1, 10).unwrap(),
+            DataFrame {
+                pad_length: Some(4),
+                data: b"hello",
+                padding: Some(&[0; 4]),
+            },
+        );
+
+        let stripped = frame.with_flags(Flags::END_STREAM).unwrap();
+        let OwnedFrame::Data(header, data) = &stripped else {
+            panic!("expected a DATA frame");
+        };
+        assert_eq!(Flags::END_STREAM, header.flags);
+        assert_eq!(5, header.length.length());
+        assert!(header.length.length() < frame.header().length.length());
+        assert_eq!(None, data.pad_length);
+        assert_eq!(None, data.padding);
+
+        let mut buf = Vec::new();
+        stripped.write(&mut buf).unwrap();
+        let (tail, reparsed) = Frame::parse(&buf).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(stripped, reparsed.to_owned());
+    }
+
+    #[test]
+    fn test_with_flags_beyond_max_length() {
+        let fragment = vec![0x82; 0xFF_FFFF];
+        let frame = Frame::Headers(
+            FrameHeader::new(FrameType::HEADERS, Flags::END_HEADERS, 1, 0xFF_FFFF).unwrap(),
+            HeadersFrame {
+                pad_length: None,
+                stream_dependency: None,
+                weight: None,
+                header_block_fragment: &fragment,
+                padding: None,
+            },
+        );
+
+        assert!(frame.with_flags(Flags::END_HEADERS).is_ok());
+        for flags in [Flags::PRIORITY, Flags::PADDED, Flags::PRIORITY | Flags::PADDED] {
+            assert_eq!(
+                Err(Http2ParseError::FrameSize),
+                frame.with_flags(Flags::END_HEADERS | flags)
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        use crate::test_corpus::ping_frame_bytes;
+
+        let bytes = ping_frame_bytes(0x0102_0304_0506_0708, true);
+        let (_, frame) = OwnedFrame::parse(&bytes).unwrap();
+
+        let json = serde_json::to_string(&frame).unwrap();
+        assert!(json.starts_with("{\"Ping\":"));
+        assert_eq!(frame, serde_json::from_str::<OwnedFrame>(&json).unwrap());
+
+        let (_, frame) = OwnedFrame::parse(&data_frame_bytes(1, b"hi", None)).unwrap();
+        let json = serde_json::to_string(&frame).unwrap();
+        assert!(json.contains("\"data\":\"6869\""));
+        assert_eq!(frame, serde_json::from_str::<OwnedFrame>(&json).unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_postcard_round_trip() {
+        use crate::test_corpus::goaway_frame_bytes;
+
+        let frames = [
+            data_frame_bytes(1, b"hi", Some(3)),
+            data_frame_bytes(1, b"hi", None),
+            goaway_frame_bytes(7, ErrorCode::NO_ERROR, b"bye"),
+            goaway_frame_bytes(7, ErrorCode::NO_ERROR, b""),
+        ];
+        for bytes in &frames {
+            let (_, frame) = OwnedFrame::parse(bytes).unwrap();
+            let encoded = postcard::to_allocvec(&frame).unwrap();
+            assert_eq!(frame, postcard::from_bytes::<OwnedFrame>(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_cow_frame_into_owned() {
+        let mut bytes = data_frame_bytes(3, b"tunnel", None);
+        let (_, frame) = Frame::parse(&bytes).unwrap();
+        let cow = frame.into_cow();
+        assert!(cow.is_borrowed());
+
+        let owned: CowFrame<'static> = cow.into_owned();
+        bytes.fill(0);
+        drop(bytes);
+
+        assert!(!owned.is_borrowed());
+        let Frame::Data(header, data) = owned.as_frame() else {
+            panic!("expected a DATA frame");
+        };
+        assert_eq!(3, header.stream_identifier.stream_identifier());
+        assert_eq!(b"tunnel", data.data);
+    }
+}
@@ -3,11 +3,23 @@ use nom::{
 };
 
 use crate::{
+    error::Http2ParseError,
     flags::Flags,
     frames::{
-        ContinuationFrame, DataFrame, ErrorCode, Frame, FrameHeader, FrameHeaderLength, FrameType, GoAwayFrame, HeadersFrame, PingFrame, PriorityFrame, PushPromiseFrame, RstStreamFrame, SettingsFrame, SettingsParameter, SettingsParameterFrame, StreamDependency, StreamIdentifier, WindowSizeIncrement, WindowUpdateFrame
+        ContinuationFrame, DataFrame, ErrorCode, Frame, FrameHeader, FrameHeaderLength, FrameType, GoAwayFrame, HeadersFrame, ParsedFrame, PingFrame, PriorityFrame, PushPromiseFrame, RstStreamFrame, SettingsFrame, SettingsParameter, SettingsParameterFrame, SettingsView, StreamDependency, StreamIdentifier, WindowSizeIncrement, WindowUpdateFrame
     },
+    options::ParseOptions,
+    settings::Http2Settings,
 };
+#[cfg(feature = "rfc7838")]
+use alloc::vec::Vec;
+#[cfg(feature = "rfc7838")]
+use nom::{character::complete::char, combinator::all_consuming, multi::separated_list1, Parser};
+#[cfg(feature = "rfc7838")]
+use crate::frames::{AltSvcEntry, AltSvcFrame};
+#[cfg(feature = "rfc8336")]
+use crate::frames::{OriginEntry, OriginEntryIter, OriginFrame};
+use zerocopy::FromBytes;
 
 pub fn parse_optional_padding_length<'a>(
     bytes: &'a [u8],
@@ -82,26 +94,312 @@ pub fn parse_payload(bytes: &[u8], length: u32) -> IResult<&[u8], &[u8], nom::er
     take(length)(bytes)
 }
 
+/// Splits off a frame's declared payload, returning `(tail, payload)`, so that the parsers of
+/// the payload's fields can never read into the next frame.
+fn parse_frame_payload<'a>(
+    bytes: &'a [u8],
+    length: &FrameHeaderLength,
+) -> IResult<&'a [u8], &'a [u8], nom::error::Error<&'a [u8]>> {
+    take(length.length())(bytes)
+}
+
+/// Returns the length left for the data or header block fragment of a frame declaring `length`
+/// octets, after the pad length octet, `fixed` octets of other fields, and the padding.
+///
+/// When the pad length octet, the other fields, and the padding together exceed the declared
+/// length, the frame is rejected with `ErrorKind::TooLarge`, which surfaces as
+/// [`Http2ParseError::FrameSize`].
+fn unpadded_len(
+    bytes: &[u8],
+    length: u32,
+    fixed: u32,
+    maybe_pad_len: Option<u8>,
+) -> IResult<&[u8], u32, nom::error::Error<&[u8]>> {
+    let overhead = maybe_pad_len.map_or(0, |pad_len| 1 + u32::from(pad_len)) + fixed;
+    length.checked_sub(overhead).map(|len| (bytes, len)).ok_or_else(|| {
+        nom::Err::Error(nom::error::Error::new(
+            bytes,
+            nom::error::ErrorKind::TooLarge,
+        ))
+    })
+}
+
+/// Returns the smallest payload length a frame of `frame_type` with `flags` can declare: the
+/// fixed fields of its type plus any optional fields its PADDED and PRIORITY flags imply.
+pub fn required_min_len(frame_type: FrameType, flags: &Flags) -> u32 {
+    let padded = u32::from(flags.contains(Flags::PADDED));
+    match frame_type {
+        FrameType::DATA => padded,
+        FrameType::HEADERS => padded + if flags.contains(Flags::PRIORITY) { 5 } else { 0 },
+        FrameType::PRIORITY => 5,
+        FrameType::RST_STREAM | FrameType::WINDOW_UPDATE => 4,
+        FrameType::PUSH_PROMISE => padded + 4,
+        FrameType::PING | FrameType::GOAWAY => 8,
+        FrameType::ALTSVC => 2,
+        _ => 0,
+    }
+}
+
+/// Rejects a header block fragment longer than `options.max_header_fragment_len`.
+fn check_header_fragment_len(
+    fragment: &[u8],
+    options: &ParseOptions,
+) -> Result<(), Http2ParseError> {
+    match options.max_header_fragment_len {
+        Some(max_len) if fragment.len() > max_len => {
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the number of bytes one frame occupies, header included, from its 9-byte header,
+/// so that frames can be skipped without being parsed.
+pub fn frame_total_len(header_bytes: &[u8; 9]) -> usize {
+    let [a, b, c, ..] = *header_bytes;
+    9 + FrameHeaderLength::from_be_bytes(&[a, b, c]).length() as usize
+}
+
+/// Reads only the length and type of a frame from the first 4 header octets, leaving flags and
+/// stream id undecoded, for callers that route by type and skip by length. Returns `None` if
+/// fewer than 4 octets are available.
+pub fn peek_type_and_length(bytes: &[u8]) -> Option<(FrameType, u32)> {
+    let [a, b, c, frame_type, ..] = *bytes else {
+        return None;
+    };
+    Some((FrameType::from(frame_type), u32::from_be_bytes([0, a, b, c])))
+}
+
+/// Scans `bytes` for the first offset holding a plausible frame header, for resynchronizing a
+/// lenient reader after a framing error. Callers pass the input past the header that failed.
+///
+/// A header is plausible if its type is assigned, its length is within `max_frame_size` and
+/// suits the type, it sets no flags the type does not define, its reserved bit is clear, and
+/// its stream identifier suits the type. This is a heuristic: payload bytes can look like a
+/// header, so whatever is found should still be parsed with care.
+pub fn resync(bytes: &[u8], max_frame_size: u32) -> Option<usize> {
+    bytes.windows(9).position(|window| {
+        let Ok((_, header)) = FrameHeader::parse(window) else {
+            return false;
+        };
+        let length = header.length.length();
+        FrameType::try_from_u8(u8::from(&header.frame_type)).is_ok()
+            && length <= max_frame_size
+            && header.frame_type.fixed_payload_len().is_none_or(|fixed| fixed == length)
+            && header.frame_type.valid_flags().contains(header.flags)
+            && window[5] & 0x80 == 0
+            && header.validate_stream_identifier().is_ok()
+    })
+}
+
+/// Returns the length of the remaining input as a `u32` without consuming it.
+///
+/// Callers slice the input to a 24-bit declared frame length first, so this cannot fail for
+/// well-formed callers; a slice too long for `u32` is reported as `ErrorKind::TooLarge` rather
+/// than being clamped.
+pub fn parse_remaining_length(bytes: &[u8]) -> IResult<&[u8], u32, nom::error::Error<&[u8]>> {
+    u32::try_from(bytes.len()).map(|len| (bytes, len)).map_err(|_| {
+        nom::Err::Error(nom::error::Error::new(
+            bytes,
+            nom::error::ErrorKind::TooLarge,
+        ))
+    })
+}
+
+/// Parses one Origin-Entry. Entries whose bytes are not printable ASCII are still consumed but
+/// carry no `ascii_origin`, so the consumer can ignore them (RFC 8336 §2.2).
 #[cfg(feature="rfc8336")]
-pub fn parse_origin_entry(bytes: &[u8]) -> IResult<&[u8], OriginEntry, nom::error::Error<&[u8]>> {
+pub fn parse_origin_entry(bytes: &[u8]) -> IResult<&[u8], OriginEntry<'_>, nom::error::Error<&[u8]>> {
     let (bytes, origin_length) = be_u16(bytes)?;
-    let (bytes, ascii) = {
-        if origin_length > 0 {
-        let (bytes, origin_ascii) = take(origin_length)(bytes)?;
-        let ascii_origin = core::str::from_utf8(origin_ascii)
-            .map_err(|_| nom::Err::Error(nom::error::Error::new(origin_ascii, nom::error::ErrorKind::Alpha)))?;
-            (bytes, Some(ascii_origin))
-        } else {
-            (bytes, None)
-        }
+    let (bytes, origin_ascii) = take(origin_length)(bytes)?;
+    let ascii_origin = if origin_ascii.iter().all(u8::is_ascii_graphic) {
+        core::str::from_utf8(origin_ascii).ok().filter(|origin| !origin.is_empty())
+    } else {
+        None
     };
 
     Ok((bytes, OriginEntry {
         origin_length,
-        ascii_origin: ascii,
+        ascii_origin,
     }))
 }
 
+#[cfg(feature = "rfc8336")]
+impl<'a> OriginFrame<'a> {
+    /// Parses an ORIGIN payload; an empty payload is valid and simply carries no entries.
+    pub fn parse(
+        bytes: &'a [u8],
+        length: &FrameHeaderLength,
+    ) -> IResult<&'a [u8], Self, nom::error::Error<&'a [u8]>> {
+        let (tail, payload) = parse_payload(bytes, length.length())?;
+        Ok((tail, Self { payload }))
+    }
+
+    /// Returns an iterator that decodes the frame's origin entries lazily from its payload.
+    pub fn entries(&self) -> OriginEntryIter<'a> {
+        OriginEntryIter {
+            remaining: self.payload,
+        }
+    }
+
+    /// Returns the origin of the entry at `index`, scanning the entries before it without
+    /// collecting them, or `None` if the frame has fewer entries.
+    ///
+    /// A malformed entry at or before `index` is returned as an error, as is an entry at
+    /// `index` that is not a printable ASCII origin.
+    pub fn origin_at(&self, index: usize) -> Option<Result<&'a str, Http2ParseError>> {
+        for (i, entry) in self.entries().enumerate() {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(err)),
+            };
+            if i == index {
+                return Some(
+                    entry
+                        .ascii_origin
+                        .ok_or(Http2ParseError::Malformed(nom::error::ErrorKind::Alpha)),
+                );
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "rfc8336")]
+impl<'a> Iterator for OriginEntryIter<'a> {
+    type Item = Result<OriginEntry<'a>, Http2ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        match parse_origin_entry(self.remaining) {
+            Ok((remaining, entry)) => {
+                self.remaining = remaining;
+                Some(Ok(entry))
+            }
+            Err(err) => {
+                self.remaining = &[];
+                Some(Err(err.into()))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rfc7838")]
+impl<'a> AltSvcFrame<'a> {
+    pub fn parse(
+        bytes: &'a [u8],
+        length: &FrameHeaderLength,
+    ) -> IResult<&'a [u8], Self, nom::error::Error<&'a [u8]>> {
+        let (tail, bytes) = parse_frame_payload(bytes, length)?;
+        let (bytes, origin_length) = be_u16(bytes)?;
+        let (field_value, origin) = take(origin_length)(bytes)?;
+        Ok((tail, Self { origin, field_value }))
+    }
+
+    /// Whether the frame names its origin, as it must on stream 0. A frame on a request stream
+    /// carries an Origin-Len of 0 and applies to that stream's origin instead.
+    pub fn has_explicit_origin(&self) -> bool {
+        !self.origin.is_empty()
+    }
+
+    /// Parses the Alt-Svc field value into its alternatives, in the order given.
+    ///
+    /// The special value `clear` yields no alternatives. Parameters other than `ma` and
+    /// `persist` are skipped, as is any `persist` value other than 1 (RFC 7838 §3.1).
+    pub fn alternatives(&self) -> Result<Vec<AltSvcEntry<'a>>, Http2ParseError> {
+        let malformed = Http2ParseError::Malformed(nom::error::ErrorKind::Verify);
+        let value = core::str::from_utf8(self.field_value).map_err(|_| malformed)?;
+        let value = value.trim_matches(|c| c == ' ' || c == '\t');
+        if value == "clear" {
+            return Ok(Vec::new());
+        }
+
+        let list_separator = (alt_svc::ows, char(','), alt_svc::ows);
+        match all_consuming(separated_list1(list_separator, alt_svc::alt_value)).parse(value) {
+            Ok((_, entries)) => Ok(entries),
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                Err(Http2ParseError::Malformed(err.code))
+            }
+            Err(nom::Err::Incomplete(_)) => Err(malformed),
+        }
+    }
+}
+
+/// Parsers for the Alt-Svc header field grammar (RFC 7838 §3) over an already decoded string.
+#[cfg(feature = "rfc7838")]
+mod alt_svc {
+    use nom::{
+        bytes::complete::{take_while, take_while1},
+        character::complete::char,
+        error::{Error, ErrorKind},
+        multi::many0,
+        sequence::{preceded, separated_pair},
+        IResult, Parser,
+    };
+
+    use crate::frames::AltSvcEntry;
+
+    pub fn ows(input: &str) -> IResult<&str, &str> {
+        take_while(|c| c == ' ' || c == '\t').parse(input)
+    }
+
+    fn token(input: &str) -> IResult<&str, &str> {
+        take_while1(|c: char| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+            .parse(input)
+    }
+
+    /// Returns the contents of a quoted string, with any escapes left in place.
+    fn quoted_string(input: &str) -> IResult<&str, &str> {
+        let (input, _) = char('"').parse(input)?;
+        let mut escaped = false;
+        for (index, c) in input.char_indices() {
+            match c {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => return Ok((&input[index + 1..], &input[..index])),
+                _ => escaped = false,
+            }
+        }
+        Err(nom::Err::Error(Error::new(input, ErrorKind::Char)))
+    }
+
+    fn parameter(input: &str) -> IResult<&str, (&str, &str)> {
+        preceded(
+            (ows, char(';'), ows),
+            separated_pair(token, char('='), quoted_string.or(token)),
+        )
+        .parse(input)
+    }
+
+    pub fn alt_value(input: &str) -> IResult<&str, AltSvcEntry<'_>> {
+        let (rest, (protocol_id, authority)) =
+            separated_pair(token, char('='), quoted_string).parse(input)?;
+        let (rest, parameters) = many0(parameter).parse(rest)?;
+
+        let mut entry = AltSvcEntry {
+            protocol_id,
+            authority,
+            max_age: None,
+            persist: false,
+        };
+        for (name, value) in parameters {
+            match name {
+                "ma" => {
+                    let max_age = value
+                        .parse()
+                        .map_err(|_| nom::Err::Error(Error::new(value, ErrorKind::Digit)))?;
+                    entry.max_age = Some(max_age);
+                }
+                "persist" => entry.persist = value == "1",
+                _ => {}
+            }
+        }
+        Ok((rest, entry))
+    }
+}
+
 pub fn parse_settings_parameter_frame(
     bytes: &[u8],
 ) -> IResult<&[u8], SettingsParameterFrame, nom::error::Error<&[u8]>> {
@@ -109,7 +407,7 @@ pub fn parse_settings_parameter_frame(
     let (bytes, identifier) = be_u16(bytes).map(|(b, i)| (b, SettingsParameter::from(i)))?;
     let (_bytes, value) = be_u32(bytes)?;
 
-    Ok((tail, SettingsParameterFrame { identifier, value }))
+    Ok((tail, SettingsParameterFrame::new(identifier, value)))
 }
 
 impl FrameHeader {
@@ -131,6 +429,26 @@ impl FrameHeader {
             },
         ))
     }
+
+    /// Parses a frame header and, when `options.enforce_stream_identifiers` is set, rejects a
+    /// stream identifier its type does not allow before any payload byte is read.
+    ///
+    /// A buffer shorter than a header is reported as incomplete, with the exact shortfall.
+    pub fn parse_validated<'a>(
+        bytes: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<(&'a [u8], Self), Http2ParseError> {
+        if bytes.len() < 9 {
+            return Err(Http2ParseError::Incomplete {
+                needed: 9 - bytes.len(),
+            });
+        }
+        let (bytes, header) = Self::parse(bytes)?;
+        if options.enforce_stream_identifiers {
+            header.validate_stream_identifier()?;
+        }
+        Ok((bytes, header))
+    }
 }
 
 impl<'a> DataFrame<'a> {
@@ -139,15 +457,15 @@ impl<'a> DataFrame<'a> {
         length: &FrameHeaderLength,
         flags: &Flags,
     ) -> IResult<&'a [u8], Self, nom::error::Error<&'a [u8]>> {
+        let (tail, bytes) = parse_frame_payload(bytes, length)?;
         let (bytes, maybe_pad_len) = parse_optional_padding_length(bytes, flags)?;
-        let pad_len = u32::from(maybe_pad_len.unwrap_or(0));
-        let adjusted_len = length.length().saturating_sub(pad_len);
+        let (bytes, data_len) = unpadded_len(bytes, length.length(), 0, maybe_pad_len)?;
 
-        let (bytes, data_bytes) = parse_payload(bytes, adjusted_len)?;
-        let (bytes, maybe_padding_bytes) = parse_optional_padding_bytes(bytes, maybe_pad_len)?;
+        let (bytes, data_bytes) = parse_payload(bytes, data_len)?;
+        let (_bytes, maybe_padding_bytes) = parse_optional_padding_bytes(bytes, maybe_pad_len)?;
 
         Ok((
-            bytes,
+            tail,
             Self {
                 pad_length: maybe_pad_len,
                 data: data_bytes,
@@ -163,16 +481,18 @@ impl<'a> HeadersFrame<'a> {
         length: &FrameHeaderLength,
         flags: &Flags,
     ) -> IResult<&'a [u8], Self, nom::error::Error<&'a [u8]>> {
+        let (tail, bytes) = parse_frame_payload(bytes, length)?;
         let (bytes, maybe_pad_len) = parse_optional_padding_length(bytes, flags)?;
-        let pad_len = u32::from(maybe_pad_len.unwrap_or(0));
-        let adjusted_len = length.length().saturating_sub(pad_len);
         let (bytes, maybe_stream_dependency) = parse_optional_stream_dependency(bytes, flags)?;
         let (bytes, maybe_weight) = parse_optional_weight(bytes, flags)?;
-        let (bytes, header_block_fragment) = parse_payload(bytes, adjusted_len)?;
-        let (bytes, maybe_padding_bytes) = parse_optional_padding_bytes(bytes, maybe_pad_len)?;
+        let priority_len = if flags.contains(Flags::PRIORITY) { 5 } else { 0 };
+        let (bytes, fragment_len) =
+            unpadded_len(bytes, length.length(), priority_len, maybe_pad_len)?;
+        let (bytes, header_block_fragment) = parse_payload(bytes, fragment_len)?;
+        let (_bytes, maybe_padding_bytes) = parse_optional_padding_bytes(bytes, maybe_pad_len)?;
 
         Ok((
-            bytes,
+            tail,
             Self {
                 pad_length: maybe_pad_len,
                 stream_dependency: maybe_stream_dependency,
@@ -222,26 +542,55 @@ impl<'a> SettingsFrame<'a> {
             Ok((bytes, Self { parameters: None }))
         } else {
             let (tail, bytes) = take(length.length())(bytes)?;
-            if bytes.len() % 6usize != 0 {
-                return Err(nom::Err::Error(nom::error::Error::new(
+            let parameters = <[SettingsParameterFrame]>::ref_from_bytes(bytes).map_err(|_| {
+                nom::Err::Error(nom::error::Error::new(
                     bytes,
                     nom::error::ErrorKind::LengthValue,
-                )));
-            }
-
-            if (bytes.as_ptr() as usize) % core::mem::align_of::<SettingsParameterFrame>() != 0 {
-                return Err(nom::Err::Error(nom::error::Error::new(
-                    bytes,
-                    nom::error::ErrorKind::Verify,
-                )));
-            }
-            let count = bytes.len() / 6;
-            let ptr = bytes.as_ptr().cast::<SettingsParameterFrame>();
-            let parameters: Option<&[SettingsParameterFrame]> =
-                Some(unsafe { core::slice::from_raw_parts(ptr, count) });
+                ))
+            })?;
+            let parameters = Some(parameters);
             Ok((tail, Self { parameters }))
         }
     }
+
+    /// Parses a SETTINGS payload into a [`SettingsView`] over its raw bytes, leaving each
+    /// parameter to be decoded as the view is iterated. A payload that is not a multiple of
+    /// 6 octets fails with `ErrorKind::LengthValue`, as in [`SettingsFrame::parse`].
+    pub fn view(
+        bytes: &'a [u8],
+        length: &FrameHeaderLength,
+        flags: &Flags,
+    ) -> IResult<&'a [u8], SettingsView<'a>, nom::error::Error<&'a [u8]>> {
+        if flags.contains(Flags::ACK) {
+            return Ok((bytes, SettingsView { payload: &[] }));
+        }
+        let (tail, payload) = take(length.length())(bytes)?;
+        if payload.len() % 6 != 0 {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                payload,
+                nom::error::ErrorKind::LengthValue,
+            )));
+        }
+        Ok((tail, SettingsView { payload }))
+    }
+
+    /// Parses a SETTINGS payload and applies its parameters to `settings` in place, without
+    /// allocating. Every parameter is validated before any takes effect, so an invalid frame
+    /// leaves `settings` untouched; an ACK changes nothing. Returns the bytes after the payload.
+    pub fn parse_into(
+        bytes: &'a [u8],
+        length: &FrameHeaderLength,
+        flags: &Flags,
+        settings: &mut Http2Settings,
+    ) -> Result<&'a [u8], Http2ParseError> {
+        let (tail, frame) = Self::parse(bytes, length, flags)?;
+        let mut applied = *settings;
+        for (parameter, value) in &frame {
+            applied.apply(parameter, value)?;
+        }
+        *settings = applied;
+        Ok(tail)
+    }
 }
 
 impl<'a> PushPromiseFrame<'a> {
@@ -250,16 +599,12 @@ impl<'a> PushPromiseFrame<'a> {
         length: &FrameHeaderLength,
         flags: &Flags,
     ) -> IResult<&'a [u8], Self, nom::error::Error<&'a [u8]>> {
-        let (tail, bytes) = take(length.length())(bytes)?;
+        let (tail, bytes) = parse_frame_payload(bytes, length)?;
         let (bytes, maybe_pad_len) = parse_optional_padding_length(bytes, flags)?;
         let (bytes, promised_stream_identifier) = parse_stream_identifier(bytes)?;
+        let (bytes, fragment_len) = unpadded_len(bytes, length.length(), 4, maybe_pad_len)?;
 
-        let pad_len = u32::from(maybe_pad_len.unwrap_or(0));
-        let adjusted_len = u32::try_from(bytes.len())
-            .unwrap_or(u32::MAX)
-            .saturating_sub(pad_len);
-
-        let (bytes, header_block_fragment) = parse_payload(bytes, adjusted_len)?;
+        let (bytes, header_block_fragment) = parse_payload(bytes, fragment_len)?;
         let (_bytes, maybe_padding_bytes) = parse_optional_padding_bytes(bytes, maybe_pad_len)?;
 
         Ok((
@@ -289,7 +634,7 @@ impl<'a> GoAwayFrame<'a> {
         let (tail, bytes) = take(length.length())(bytes)?;
         let (bytes, last_stream_identifier) = parse_stream_identifier(bytes)?;
         let (bytes, error_code) = parse_error_code(bytes)?;
-        let remaining = u32::try_from(bytes.len()).unwrap_or(u32::MAX);
+        let (bytes, remaining) = parse_remaining_length(bytes)?;
         let debug_data = if remaining != 0 {
             let (_bytes, debug_data) = parse_payload(bytes, remaining)?;
             Some(debug_data)
@@ -337,54 +682,415 @@ impl<'a> ContinuationFrame<'a> {
 }
 
 impl<'a> Frame<'a> {
-    pub fn parse(bytes: &'a [u8]) {
-        let (bytes, frame_header) = FrameHeader::parse(bytes).unwrap();
-        match frame_header.frame_type {
+    /// Parses one frame with the default (lenient) [`ParseOptions`], returning the remaining input.
+    pub fn parse(bytes: &'a [u8]) -> Result<(&'a [u8], Self), Http2ParseError> {
+        Self::parse_with_options(bytes, &ParseOptions::default())
+    }
+
+    pub fn parse_with_options(
+        bytes: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<(&'a [u8], Self), Http2ParseError> {
+        // A short buffer is reported as incomplete, with the exact shortfall, before any
+        // payload parser can mistake it for a malformed frame. This covers a header that
+        // arrived without its payload, which needs the full declared length.
+        let (bytes, frame_header) = FrameHeader::parse_validated(bytes, options)?;
+
+        Self::parse_body(bytes, frame_header, options)
+    }
+
+    /// Parses one frame like [`Frame::parse`], also reporting the declared payload length and
+    /// how many payload octets the decoded fields account for, so that a proxy can spot and
+    /// normalize frames whose header and decoded payload disagree.
+    pub fn parse_detailed(bytes: &'a [u8]) -> Result<(&'a [u8], ParsedFrame<'a>), Http2ParseError> {
+        Self::parse_detailed_with_options(bytes, &ParseOptions::default())
+    }
+
+    /// Like [`Frame::parse_detailed`], with the given [`ParseOptions`]. Lenient options can
+    /// decode fewer octets than declared, such as a SETTINGS payload with a trailing partial
+    /// parameter when `enforce_settings_length` is unset.
+    pub fn parse_detailed_with_options(
+        bytes: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<(&'a [u8], ParsedFrame<'a>), Http2ParseError> {
+        let (tail, frame) = Self::parse_with_options(bytes, options)?;
+        let parsed = ParsedFrame {
+            declared_len: frame.header().length.length() as usize,
+            consumed_len: frame.payload_len(),
+            frame,
+        };
+        Ok((tail, parsed))
+    }
+
+    /// Parses a frame whose header has already been read, dispatching on `header`'s type.
+    ///
+    /// `payload` must start at the first payload octet; anything past the declared length is
+    /// ignored.
+    pub fn parse_parts(header: &FrameHeader, payload: &'a [u8]) -> Result<Self, Http2ParseError> {
+        let (_, frame) = Self::parse_body(payload, *header, &ParseOptions::default())?;
+        Ok(frame)
+    }
+
+    fn parse_body(
+        bytes: &'a [u8],
+        frame_header: FrameHeader,
+        options: &ParseOptions,
+    ) -> Result<(&'a [u8], Self), Http2ParseError> {
+        let length = &frame_header.length;
+        let flags = &frame_header.flags;
+        let declared_len = length.length() as usize;
+        if bytes.len() < declared_len {
+            return Err(Http2ParseError::Incomplete {
+                needed: declared_len - bytes.len(),
+            });
+        }
+        if frame_header
+            .frame_type
+            .fixed_payload_len()
+            .is_some_and(|fixed_len| length.length() != fixed_len)
+        {
+            return Err(Http2ParseError::FrameSize);
+        }
+        if length.length() < required_min_len(frame_header.frame_type, flags) {
+            return Err(Http2ParseError::FrameSize);
+        }
+        // A SETTINGS ACK must be empty (RFC 7540 §6.5); any payload it declared would be left
+        // in the tail and misread as the next frame header.
+        if frame_header.frame_type == FrameType::SETTINGS
+            && flags.contains(Flags::ACK)
+            && length.length() != 0
+        {
+            return Err(Http2ParseError::FrameSize);
+        }
+        // A SETTINGS payload is a whole number of 6-octet parameters (RFC 7540 §6.5).
+        if frame_header.frame_type == FrameType::SETTINGS
+            && options.enforce_settings_length
+            && !length.length().is_multiple_of(6)
+        {
+            return Err(Http2ParseError::FrameSize);
+        }
+
+        let (tail, frame) = match frame_header.frame_type {
             FrameType::DATA => {
-                DataFrame::parse(bytes, &frame_header.length, &frame_header.flags).unwrap();
+                let (tail, frame) = DataFrame::parse(bytes, length, flags)?;
+                (tail, Frame::Data(frame_header, frame))
             }
             FrameType::HEADERS => {
-                HeadersFrame::parse(bytes, &frame_header.length, &frame_header.flags).unwrap();
+                let (tail, frame) = HeadersFrame::parse(bytes, length, flags)?;
+                check_header_fragment_len(frame.header_block_fragment, options)?;
+                (tail, Frame::Headers(frame_header, frame))
             }
             FrameType::PRIORITY => {
-                PriorityFrame::parse(bytes).unwrap();
+                let (tail, frame) = PriorityFrame::parse(bytes)?;
+                (tail, Frame::Priority(frame_header, frame))
             }
             FrameType::RST_STREAM => {
-                RstStreamFrame::parse(bytes).unwrap();
+                let (tail, frame) = RstStreamFrame::parse(bytes)?;
+                (tail, Frame::RstStream(frame_header, frame))
             }
             FrameType::SETTINGS => {
-                SettingsFrame::parse(bytes, &frame_header.length, &frame_header.flags).unwrap();
+                if length.length() as usize / 6 > options.max_settings_params {
+                    return Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM));
+                }
+                // Only whole parameters are decoded; a partial one left by lenient options is
+                // skipped with the rest of the payload.
+                let whole_len = length.with_length(length.length() - length.length() % 6);
+                let (_, frame) = SettingsFrame::parse(bytes, &whole_len, flags)?;
+                (&bytes[declared_len..], Frame::Settings(frame_header, frame))
             }
             FrameType::PUSH_PROMISE => {
-                PushPromiseFrame::parse(bytes, &frame_header.length, &frame_header.flags).unwrap();
+                let (tail, frame) = PushPromiseFrame::parse(bytes, length, flags)?;
+                check_header_fragment_len(frame.header_block_fragment, options)?;
+                (tail, Frame::PushPromise(frame_header, frame))
             }
             FrameType::PING => {
-                PingFrame::parse(bytes).unwrap();
+                let (tail, frame) = PingFrame::parse(bytes)?;
+                (tail, Frame::Ping(frame_header, frame))
             }
             FrameType::GOAWAY => {
-                GoAwayFrame::parse(bytes, &frame_header.length).unwrap();
+                let (tail, frame) = GoAwayFrame::parse(bytes, length)?;
+                (tail, Frame::GoAway(frame_header, frame))
             }
             FrameType::WINDOW_UPDATE => {
-                WindowUpdateFrame::parse(bytes).unwrap();
+                let (tail, frame) = WindowUpdateFrame::parse(bytes)?;
+                (tail, Frame::WindowUpdate(frame_header, frame))
             }
             FrameType::CONTINUATION => {
-                ContinuationFrame::parse(bytes, &frame_header.length).unwrap();
+                let (tail, frame) = ContinuationFrame::parse(bytes, length)?;
+                check_header_fragment_len(frame.header_block_fragment, options)?;
+                (tail, Frame::Continuation(frame_header, frame))
+            }
+            // ALTSVC defines no flags. It names its origin on stream 0 and applies to the
+            // stream's own origin elsewhere; any other combination is ignored (RFC 7838 §4).
+            #[cfg(feature = "rfc7838")]
+            FrameType::ALTSVC => {
+                let (tail, frame) = AltSvcFrame::parse(bytes, length)?;
+                let on_stream_zero = frame_header.stream_identifier.stream_identifier() == 0;
+                if frame.origin.is_empty() == on_stream_zero {
+                    (tail, Frame::Ignored(frame_header, &bytes[..declared_len]))
+                } else {
+                    (tail, Frame::AltSvc(frame_header, frame))
+                }
+            }
+            #[cfg(not(feature = "rfc7838"))]
+            FrameType::ALTSVC => Self::parse_unknown(bytes, frame_header, options)?,
+            // ORIGIN defines no flags and receivers must ignore them, as they must an ORIGIN
+            // frame on any stream but 0 (RFC 8336 §2).
+            #[cfg(feature = "rfc8336")]
+            FrameType::ORIGIN if frame_header.stream_identifier.stream_identifier() != 0 => {
+                let (tail, payload) = parse_payload(bytes, length.length())?;
+                (tail, Frame::Ignored(frame_header, payload))
+            }
+            #[cfg(feature = "rfc8336")]
+            FrameType::ORIGIN => {
+                let (tail, frame) = OriginFrame::parse(bytes, length)?;
+                (tail, Frame::Origin(frame_header, frame))
             }
-            FrameType::ALTSVC => todo!(),
-            FrameType::ORIGIN => todo!(),
-            FrameType::UNKNOWN(_) => todo!(),
+            #[cfg(not(feature = "rfc8336"))]
+            FrameType::ORIGIN => Self::parse_unknown(bytes, frame_header, options)?,
+            FrameType::UNKNOWN(_) => Self::parse_unknown(bytes, frame_header, options)?,
+        };
+
+        Ok((tail, frame))
+    }
+
+    fn parse_unknown(
+        bytes: &'a [u8],
+        frame_header: FrameHeader,
+        options: &ParseOptions,
+    ) -> Result<(&'a [u8], Self), Http2ParseError> {
+        if options.error_on_unknown_frame_type {
+            return Err(Http2ParseError::UnknownFrameType(u8::from(&frame_header.frame_type)));
         }
+        let (tail, payload) = parse_payload(bytes, frame_header.length.length())?;
+        Ok((tail, Frame::Unknown(frame_header, payload)))
+    }
+}
+
+/// Parses input holding exactly one frame. Bytes past the end of the frame fail with
+/// `Malformed(Eof)`, as for nom's `all_consuming`; use [`Frame::parse`] to read a stream.
+impl<'a> TryFrom<&'a [u8]> for Frame<'a> {
+    type Error = Http2ParseError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let (tail, frame) = Self::parse(bytes)?;
+        if !tail.is_empty() {
+            return Err(Http2ParseError::Malformed(nom::error::ErrorKind::Eof));
+        }
+        Ok(frame)
     }
 }
 
 #[cfg(test)]
 mod parse_tests {
 
+    use alloc::vec::Vec;
+
     use crate::{
+        error::Http2ParseError,
         flags::Flags,
-        frames::{FrameHeader, FrameHeaderLength, FrameType, StreamIdentifier},
+        frames::{
+            ErrorCode, Frame, FrameHeader, FrameHeaderLength, FrameType, SettingsParameter,
+            StreamIdentifier,
+        },
+        options::ParseOptions,
+        test_corpus::{
+            data_frame_bytes, frame_bytes, headers_frame_bytes, ping_frame_bytes,
+            settings_frame_bytes,
+        },
     };
 
+    use super::{frame_total_len, parse_remaining_length, peek_type_and_length, required_min_len};
+
+    #[test]
+    fn test_try_from_exactly_one_frame() {
+        let mut bytes = ping_frame_bytes(7, false);
+        let frame = Frame::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(FrameType::PING, frame.header().frame_type);
+
+        bytes.push(0x00);
+        assert_eq!(
+            Err(Http2ParseError::Malformed(nom::error::ErrorKind::Eof)),
+            Frame::try_from(bytes.as_slice())
+        );
+        assert_eq!(
+            Err(Http2ParseError::Incomplete { needed: 1 }),
+            Frame::try_from(&bytes[..16])
+        );
+    }
+
+    #[test]
+    fn test_resync_skips_garbage() {
+        use super::resync;
+
+        let ping = ping_frame_bytes(42, false);
+        let mut bytes = b"\xDE\xAD\xBE\xEF".repeat(3);
+        let offset = bytes.len();
+        bytes.extend_from_slice(&ping);
+
+        assert_eq!(Some(offset), resync(&bytes, 16_384));
+        let (tail, frame) = Frame::parse(&bytes[offset..]).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(FrameType::PING, frame.header().frame_type);
+
+        assert_eq!(None, resync(b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF", 16_384));
+        let oversized = data_frame_bytes(1, &[0; 32], None);
+        assert_eq!(None, resync(&oversized[..9], 16));
+        assert_eq!(Some(0), resync(&oversized[..9], 32));
+    }
+
+    #[test]
+    fn test_parse_detailed_lengths() {
+        let mut bytes = data_frame_bytes(1, b"hello", Some(3));
+        bytes.extend(ping_frame_bytes(1, false));
+        let (tail, parsed) = Frame::parse_detailed(&bytes).unwrap();
+
+        assert_eq!(FrameType::DATA, parsed.frame.header().frame_type);
+        assert_eq!(9, parsed.declared_len);
+        assert_eq!(parsed.declared_len, parsed.consumed_len);
+        assert_eq!(17, tail.len());
+
+        // Lenient options skip the 2 octets of a partial SETTINGS parameter.
+        let mut payload = settings_frame_bytes(&[(SettingsParameter::SETTINGS_ENABLE_PUSH, 0)]);
+        payload.drain(..9);
+        payload.extend_from_slice(&[0, 4]);
+        let mut bytes = frame_bytes(FrameType::SETTINGS, Flags::NONE, 0, &payload);
+        bytes.extend(ping_frame_bytes(1, false));
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse_detailed(&bytes));
+
+        let (tail, parsed) =
+            Frame::parse_detailed_with_options(&bytes, &ParseOptions::inspector()).unwrap();
+        assert_eq!(8, parsed.declared_len);
+        assert_eq!(6, parsed.consumed_len);
+        assert_eq!(17, tail.len());
+    }
+
+    #[test]
+    fn test_peek_type_and_length() {
+        let bytes = headers_frame_bytes(1, Flags::END_STREAM, &[0x82; 300], None);
+        assert_eq!(Some((FrameType::HEADERS, 300)), peek_type_and_length(&bytes[..4]));
+        assert_eq!(Some((FrameType::HEADERS, 300)), peek_type_and_length(&bytes));
+        assert_eq!(None, peek_type_and_length(&bytes[..3]));
+    }
+
+    #[test]
+    fn test_header_parse_validated() {
+        // Only the 9-byte header: the 6-byte payload it declares has not arrived.
+        let header = &settings_frame_bytes(&[(SettingsParameter::SETTINGS_ENABLE_PUSH, 0)])[..9];
+        let mut on_stream_1 = header.to_vec();
+        on_stream_1[8] = 1;
+
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            FrameHeader::parse_validated(&on_stream_1, &ParseOptions::endpoint())
+        );
+        let (tail, parsed) =
+            FrameHeader::parse_validated(&on_stream_1, &ParseOptions::default()).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(1, parsed.stream_identifier.stream_identifier());
+        assert!(FrameHeader::parse_validated(header, &ParseOptions::endpoint()).is_ok());
+        assert_eq!(
+            Err(Http2ParseError::Incomplete { needed: 4 }),
+            FrameHeader::parse_validated(&header[..5], &ParseOptions::endpoint())
+        );
+    }
+
+    #[test]
+    fn test_max_padding_ratio() {
+        let options = ParseOptions {
+            max_padding_ratio: Some(0.5),
+            ..ParseOptions::default()
+        };
+
+        // 18 of the 20 payload octets are padding.
+        let bytes = data_frame_bytes(1, b"x", Some(18));
+        let (_, frame) = Frame::parse_with_options(&bytes, &options).unwrap();
+        assert_eq!(Some(0.9), frame.padding_ratio());
+        assert!(frame.is_over_padded(&options));
+        assert!(!frame.is_over_padded(&ParseOptions::default()));
+
+        let bytes = data_frame_bytes(1, b"0123456789", Some(5));
+        let (_, frame) = Frame::parse_with_options(&bytes, &options).unwrap();
+        assert!(!frame.is_over_padded(&options));
+
+        let bytes = headers_frame_bytes(1, Flags::NONE, &[0x82; 16], None);
+        let (_, frame) = Frame::parse_with_options(&bytes, &options).unwrap();
+        assert_eq!(None, frame.padding_ratio());
+        assert!(!frame.is_over_padded(&options));
+    }
+
+    #[test]
+    fn test_max_header_fragment_len() {
+        use crate::test_corpus::{continuation_frame_bytes, push_promise_frame_bytes};
+
+        let options = ParseOptions {
+            max_header_fragment_len: Some(16),
+            ..ParseOptions::default()
+        };
+        let error = Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+        let oversized = [
+            headers_frame_bytes(1, Flags::NONE, &[0x82; 17], Some((0, 15))),
+            push_promise_frame_bytes(1, 2, &[0x82; 17]),
+            continuation_frame_bytes(1, &[0x82; 17], true),
+        ];
+        for bytes in &oversized {
+            assert!(Frame::parse(bytes).is_ok());
+            assert_eq!(error, Frame::parse_with_options(bytes, &options));
+        }
+
+        // The priority fields do not count towards the fragment.
+        let bytes = headers_frame_bytes(1, Flags::NONE, &[0x82; 16], Some((0, 15)));
+        assert!(Frame::parse_with_options(&bytes, &options).is_ok());
+    }
+
+    #[test]
+    fn test_settings_parse_into_stack_buffer() {
+        use crate::{frames::SettingsFrame, settings::Http2Settings};
+
+        let payload: [u8; 12] = [
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x00, // SETTINGS_ENABLE_PUSH = 0
+            0x00, 0x05, 0x00, 0x00, 0x80, 0x00, // SETTINGS_MAX_FRAME_SIZE = 32768
+        ];
+        let length = FrameHeaderLength::new().with_length(12);
+        let mut settings = Http2Settings::default();
+        let tail = SettingsFrame::parse_into(&payload, &length, &Flags::NONE, &mut settings);
+        assert_eq!(Ok(&[][..]), tail);
+        assert!(!settings.enable_push.get());
+        assert_eq!(32_768, settings.max_frame_size.get());
+
+        let invalid: [u8; 12] = [
+            0x00, 0x04, 0x00, 0x00, 0xFF, 0xFF, // SETTINGS_INITIAL_WINDOW_SIZE = 65535
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x02, // SETTINGS_ENABLE_PUSH = 2
+        ];
+        let before = settings;
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            SettingsFrame::parse_into(&invalid, &length, &Flags::NONE, &mut settings)
+        );
+        assert_eq!(before, settings);
+    }
+
+    #[test]
+    fn test_remaining_length_overflow_is_frame_size() {
+        let bytes = [0u8; 5];
+        assert_eq!(Ok((&bytes[..], 5)), parse_remaining_length(&bytes));
+
+        let too_large = nom::Err::Error(nom::error::Error::new(
+            &bytes[..],
+            nom::error::ErrorKind::TooLarge,
+        ));
+        assert_eq!(Http2ParseError::FrameSize, Http2ParseError::from(too_large));
+    }
+
+    #[test]
+    fn test_frame_total_len() {
+        let header = [0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        assert_eq!(16_393, frame_total_len(&header));
+
+        let bytes = data_frame_bytes(1, b"hello", None);
+        assert_eq!(bytes.len(), frame_total_len(bytes[..9].try_into().unwrap()));
+    }
+
     #[test]
     fn test_parse_frame_header_from_bytes() {
         let header_0: [u8; 9] = [0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
@@ -406,7 +1112,7 @@ mod parse_tests {
             },
             parsed_header_0.1
         );
-        assert_eq!(true, parsed_header_0.0.is_empty());
+        assert!(parsed_header_0.0.is_empty());
 
         assert_eq!(
             FrameHeader {
@@ -417,7 +1123,7 @@ mod parse_tests {
             },
             parsed_header_1.1
         );
-        assert_eq!(true, parsed_header_1.0.is_empty());
+        assert!(parsed_header_1.0.is_empty());
 
         assert_eq!(
             FrameHeader {
@@ -428,7 +1134,471 @@ mod parse_tests {
             },
             parsed_header_2.1
         );
-        assert_eq!(false, parsed_header_2.0.is_empty());
-        assert_eq!(true, parsed_header_3.is_err())
+        assert!(!parsed_header_2.0.is_empty());
+        assert!(parsed_header_3.is_err())
+    }
+
+    #[test]
+    fn test_parse_parts_data() {
+        let header = FrameHeader {
+            length: FrameHeaderLength::new().with_length(5),
+            frame_type: FrameType::DATA,
+            flags: Flags::END_STREAM,
+            stream_identifier: StreamIdentifier::new().with_stream_identifier(1),
+        };
+
+        match Frame::parse_parts(&header, b"hello").unwrap() {
+            Frame::Data(parsed, data) => {
+                assert_eq!(header, parsed);
+                assert_eq!(b"hello", data.data);
+            }
+            _ => panic!("expected a DATA frame"),
+        }
+        assert_eq!(
+            Err(Http2ParseError::Incomplete { needed: 2 }),
+            Frame::parse_parts(&header, b"hel")
+        );
+    }
+
+    #[test]
+    fn test_fixed_payload_len_mismatch() {
+        let short_ping = frame_bytes(FrameType::PING, Flags::NONE, 0, &[0; 4]);
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&short_ping));
+
+        let long_ping = frame_bytes(FrameType::PING, Flags::NONE, 0, &[0; 12]);
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&long_ping));
+
+        let long_rst = frame_bytes(FrameType::RST_STREAM, Flags::NONE, 1, &[0; 5]);
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&long_rst));
+        assert_eq!(None, FrameType::DATA.fixed_payload_len());
+    }
+
+    #[test]
+    fn test_settings_ack_with_payload() {
+        let mut bytes = frame_bytes(FrameType::SETTINGS, Flags::ACK, 0, &[0; 6]);
+        bytes.extend(ping_frame_bytes(1, false));
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&bytes));
+    }
+
+    #[test]
+    fn test_padded_frames_stay_within_payload() {
+        let padded = Flags::PADDED | Flags::END_HEADERS;
+        let frames = [
+            data_frame_bytes(1, b"abc", Some(2)),
+            frame_bytes(
+                FrameType::HEADERS,
+                padded | Flags::PRIORITY,
+                3,
+                &[1, 0, 0, 0, 1, 16, 0x82, 0],
+            ),
+            frame_bytes(FrameType::PUSH_PROMISE, padded, 3, &[2, 0, 0, 0, 4, 0x82, 0, 0]),
+        ];
+        let bytes = frames.concat();
+
+        let mut remaining = bytes.as_slice();
+        for expected in &frames {
+            let (tail, frame) = Frame::parse(remaining).unwrap();
+            assert_eq!(expected.len() - 9, frame.payload_len());
+            assert_eq!(remaining.len() - expected.len(), tail.len());
+            remaining = tail;
+        }
+        assert!(remaining.is_empty());
+    }
+
+    #[test]
+    fn test_padded_data_len() {
+        let mut bytes = data_frame_bytes(1, b"abc", Some(2));
+        assert_eq!(6, bytes[2]);
+        let next = ping_frame_bytes(7, false);
+        bytes.extend_from_slice(&next);
+
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        let Frame::Data(_, data) = frame else {
+            panic!("expected a DATA frame");
+        };
+        assert_eq!(3, data.data.len());
+        assert_eq!(Some(&[0, 0][..]), data.padding);
+        assert_eq!(next.as_slice(), tail);
+    }
+
+    #[test]
+    fn test_headers_priority_fragment_len() {
+        let mut bytes = headers_frame_bytes(3, Flags::NONE, b"\x82\x84", Some((1, 255)));
+        let next = ping_frame_bytes(7, false);
+        bytes.extend_from_slice(&next);
+
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        let Frame::Headers(_, headers) = frame else {
+            panic!("expected a HEADERS frame");
+        };
+        assert_eq!(b"\x82\x84", headers.header_block_fragment);
+        assert_eq!(Some(255), headers.weight);
+        assert_eq!(next.as_slice(), tail);
+
+        let mut padded = frame_bytes(FrameType::HEADERS, Flags::PADDED, 1, &[2, 0x82]);
+        padded.extend_from_slice(&next);
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&padded));
+    }
+
+    #[test]
+    fn test_headers_padding_exceeds_length() {
+        let flags = Flags::END_HEADERS | Flags::PADDED | Flags::PRIORITY;
+        let bytes = frame_bytes(FrameType::HEADERS, flags, 1, &[4, 0, 0, 0, 0, 15, 0x82]);
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&bytes));
+
+        let bytes = frame_bytes(FrameType::HEADERS, flags, 1, &[1, 0, 0, 0, 0, 15, 0x82, 0]);
+        assert!(Frame::parse(&bytes).is_ok());
+    }
+
+    #[test]
+    fn test_headers_empty_fragment() {
+        let bytes = headers_frame_bytes(1, Flags::NONE, &[], Some((0, 15)));
+        assert_eq!(5, bytes[2]);
+
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        let Frame::Headers(header, headers) = frame else {
+            panic!("expected a HEADERS frame");
+        };
+        assert!(tail.is_empty());
+        assert!(header.flags.contains(Flags::END_HEADERS));
+        assert_eq!(Some(15), headers.weight);
+        assert!(headers.header_block_fragment.is_empty());
+
+        let padded = Flags::END_HEADERS | Flags::PADDED | Flags::PRIORITY;
+        let bytes = frame_bytes(FrameType::HEADERS, padded, 1, &[1, 0, 0, 0, 0, 15, 0]);
+        let (_, frame) = Frame::parse(&bytes).unwrap();
+        let Frame::Headers(_, headers) = frame else {
+            panic!("expected a HEADERS frame");
+        };
+        assert!(headers.header_block_fragment.is_empty());
+        assert_eq!(Some(&[0][..]), headers.padding);
+    }
+
+    #[test]
+    fn test_parse_unknown_frame_type() {
+        let bytes: [u8; 12] = [
+            0x00, 0x00, 0x03, 0x42, 0x00, 0x00, 0x00, 0x00, 0x01, 0xAA, 0xBB, 0xCC,
+        ];
+
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        assert!(tail.is_empty());
+        match frame {
+            Frame::Unknown(header, payload) => {
+                assert_eq!(FrameType::UNKNOWN(0x42), header.frame_type);
+                assert_eq!(&[0xAA, 0xBB, 0xCC], payload);
+            }
+            _ => panic!("expected an unknown frame"),
+        }
+
+        let strict = ParseOptions {
+            error_on_unknown_frame_type: true,
+            ..ParseOptions::default()
+        };
+        assert!(matches!(
+            Frame::parse_with_options(&bytes, &strict),
+            Err(Http2ParseError::UnknownFrameType(0x42))
+        ));
+    }
+
+    #[cfg(feature = "rfc8336")]
+    #[test]
+    fn test_origin_entries_lazy() {
+        use crate::frames::{OriginEntry, OriginFrame};
+
+        let payload = b"\x00\x13https://example.com\x00\x0fhttps://a.b.org";
+        let length = FrameHeaderLength::new().with_length(payload.len() as u32);
+        let (tail, origin) = OriginFrame::parse(payload, &length).unwrap();
+        assert!(tail.is_empty());
+
+        let mut entries = origin.entries();
+        assert_eq!(
+            Some(Ok(OriginEntry {
+                origin_length: 19,
+                ascii_origin: Some("https://example.com"),
+            })),
+            entries.next()
+        );
+        assert_eq!(
+            Some(Ok(OriginEntry {
+                origin_length: 15,
+                ascii_origin: Some("https://a.b.org"),
+            })),
+            entries.next()
+        );
+        assert_eq!(None, entries.next());
+    }
+
+    #[cfg(feature = "rfc8336")]
+    #[test]
+    fn test_origin_at() {
+        use crate::frames::OriginFrame;
+
+        let origin = OriginFrame {
+            payload: b"\x00\x13https://example.com\x00\x0fhttps://a.b.org",
+        };
+        assert_eq!(Some(Ok("https://example.com")), origin.origin_at(0));
+        assert_eq!(Some(Ok("https://a.b.org")), origin.origin_at(1));
+        assert_eq!(None, origin.origin_at(5));
+
+        let truncated = OriginFrame {
+            payload: b"\x00\x13https://example.com\x00\x0fhttps",
+        };
+        assert!(matches!(truncated.origin_at(1), Some(Err(_))));
+    }
+
+    #[cfg(feature = "rfc8336")]
+    #[test]
+    fn test_origin_entry_control_bytes() {
+        use super::parse_origin_entry;
+
+        let bytes = b"\x00\x05a\x00b.c\x00\x03a.b";
+        let (tail, entry) = parse_origin_entry(bytes).unwrap();
+
+        assert_eq!(5, entry.origin_length);
+        assert_eq!(None, entry.ascii_origin);
+        assert!(!entry.is_valid());
+
+        let (tail, entry) = parse_origin_entry(tail).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(Some("a.b"), entry.ascii_origin);
+        assert!(entry.is_valid());
+    }
+
+    #[test]
+    fn test_header_without_payload_is_incomplete() {
+        for frame_type in [FrameType::DATA, FrameType::HEADERS, FrameType::GOAWAY] {
+            let bytes = frame_bytes(frame_type, Flags::PADDED, 1, &[0; 100]);
+            assert_eq!(
+                Err(Http2ParseError::Incomplete { needed: 100 }),
+                Frame::parse(&bytes[..9]),
+                "{frame_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_settings_is_incomplete() {
+        let mut bytes = settings_frame_bytes(&[
+            (SettingsParameter::SETTINGS_ENABLE_PUSH, 0),
+            (SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 32_768),
+        ]);
+        bytes.truncate(9 + 6);
+        assert_eq!(
+            Err(Http2ParseError::Incomplete { needed: 6 }),
+            Frame::parse(&bytes)
+        );
+        assert_eq!(
+            Err(Http2ParseError::Incomplete { needed: 4 }),
+            Frame::parse(&bytes[..5])
+        );
+
+        let bytes = frame_bytes(FrameType::SETTINGS, Flags::NONE, 0, &[0; 7]);
+        assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&bytes));
+    }
+
+    #[test]
+    fn test_inspector_and_endpoint_presets() {
+        let mut bytes = settings_frame_bytes(&[(SettingsParameter::SETTINGS_ENABLE_PUSH, 0)]);
+        bytes[8] = 1;
+
+        let (tail, frame) = Frame::parse_with_options(&bytes, &ParseOptions::inspector()).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(1, frame.header().stream_identifier.stream_identifier());
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            Frame::parse_with_options(&bytes, &ParseOptions::endpoint())
+        );
+    }
+
+    #[test]
+    fn test_flags_imply_min_length() {
+        let padded = Flags::PADDED;
+        let prioritized = Flags::PADDED | Flags::PRIORITY;
+        assert_eq!(1, required_min_len(FrameType::DATA, &padded));
+        assert_eq!(6, required_min_len(FrameType::HEADERS, &prioritized));
+        assert_eq!(5, required_min_len(FrameType::PUSH_PROMISE, &padded));
+        assert_eq!(0, required_min_len(FrameType::CONTINUATION, &prioritized));
+
+        let cases = [
+            frame_bytes(FrameType::DATA, padded, 1, &[]),
+            frame_bytes(FrameType::HEADERS, Flags::PRIORITY, 1, &[0, 0, 0, 3]),
+            frame_bytes(FrameType::HEADERS, prioritized, 1, &[0, 0, 0, 0, 3]),
+            frame_bytes(FrameType::PUSH_PROMISE, Flags::NONE, 1, &[0, 0, 2]),
+            frame_bytes(FrameType::PUSH_PROMISE, padded, 1, &[0, 0, 0, 2]),
+        ];
+        for bytes in cases {
+            assert_eq!(Err(Http2ParseError::FrameSize), Frame::parse(&bytes));
+        }
+    }
+
+    #[test]
+    fn test_max_settings_params() {
+        let parameters: Vec<_> = (0..100)
+            .map(|_| (SettingsParameter::SETTINGS_ENABLE_PUSH, 0))
+            .collect();
+        let bytes = settings_frame_bytes(&parameters);
+        let options = ParseOptions {
+            max_settings_params: 64,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::ENHANCE_YOUR_CALM)),
+            Frame::parse_with_options(&bytes, &options).map(|_| ())
+        );
+
+        let bytes = settings_frame_bytes(&parameters[..64]);
+        assert!(Frame::parse_with_options(&bytes, &options).is_ok());
+    }
+
+    #[cfg(feature = "rfc8336")]
+    #[test]
+    fn test_origin_on_nonzero_stream_is_ignored() {
+        let bytes = frame_bytes(FrameType::ORIGIN, Flags::NONE, 3, b"\x00\x03a.b");
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        assert!(tail.is_empty());
+        let Frame::Ignored(header, payload) = frame else {
+            panic!("expected an ignored frame");
+        };
+        assert_eq!(3, header.stream_identifier.stream_identifier());
+        assert_eq!(5, header.length.length());
+        assert_eq!(b"\x00\x03a.b", payload);
+
+        let mut buf = Vec::new();
+        frame.write(&mut buf).unwrap();
+        assert_eq!(bytes, buf);
+        assert_eq!(frame.wire_len(), buf.len());
+    }
+
+    #[cfg(feature = "rfc7838")]
+    #[test]
+    fn test_altsvc_origin_stream_mismatch_is_ignored() {
+        use crate::test_corpus::altsvc_frame_bytes;
+
+        let cases = [
+            (altsvc_frame_bytes(0, b"", b"clear"), true),
+            (altsvc_frame_bytes(1, b"https://example.com", b"clear"), true),
+            (altsvc_frame_bytes(1, b"", b"clear"), false),
+        ];
+        for (bytes, ignored) in cases {
+            let (_, frame) = Frame::parse(&bytes).unwrap();
+            assert_eq!(ignored, matches!(frame, Frame::Ignored(..)));
+            let mut buf = Vec::new();
+            frame.write(&mut buf).unwrap();
+            assert_eq!(bytes, buf);
+        }
+    }
+
+    #[cfg(feature = "rfc8336")]
+    #[test]
+    fn test_origin_ignores_flags() {
+        let payload = b"\x00\x13https://example.com";
+        let plain = frame_bytes(FrameType::ORIGIN, Flags::NONE, 0, payload);
+        let flagged = frame_bytes(FrameType::ORIGIN, Flags(0xFF), 0, payload);
+
+        let (_, Frame::Origin(plain_header, plain_frame)) = Frame::parse(&plain).unwrap() else {
+            panic!("expected an ORIGIN frame");
+        };
+        let (tail, Frame::Origin(flagged_header, flagged_frame)) = Frame::parse(&flagged).unwrap()
+        else {
+            panic!("expected an ORIGIN frame");
+        };
+
+        assert!(tail.is_empty());
+        assert_eq!(plain_frame, flagged_frame);
+        assert_eq!(plain_header.length, flagged_header.length);
+        assert_eq!(
+            plain_frame.entries().collect::<Vec<_>>(),
+            flagged_frame.entries().collect::<Vec<_>>()
+        );
+    }
+
+    #[cfg(feature = "rfc8336")]
+    #[test]
+    fn test_empty_origin_frame() {
+        use crate::connection::Connection;
+
+        let bytes = frame_bytes(FrameType::ORIGIN, Flags::NONE, 0, &[]);
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        let Frame::Origin(_, origin) = frame else {
+            panic!("expected an ORIGIN frame");
+        };
+
+        assert!(tail.is_empty());
+        assert!(origin.payload.is_empty());
+        assert_eq!(None, origin.entries().next());
+        assert_eq!(Ok(None), Connection::new().on_frame(&frame));
+        assert_eq!(Some((9, 0)), Frame::dissect(&bytes).unwrap().field("origin_entries"));
+    }
+
+    #[cfg(feature = "rfc7838")]
+    #[test]
+    fn test_alt_svc_alternatives() {
+        use crate::frames::AltSvcEntry;
+
+        let mut payload = Vec::from(*b"\x00\x13https://example.com");
+        payload.extend_from_slice(b"h2=\":443\"; ma=3600, h3=\"alt.example.com:443\"; persist=1");
+        let bytes = frame_bytes(FrameType::ALTSVC, Flags::NONE, 0, &payload);
+
+        let (tail, Frame::AltSvc(_, alt_svc)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected an ALTSVC frame");
+        };
+        assert!(tail.is_empty());
+        assert_eq!(b"https://example.com", alt_svc.origin);
+        assert_eq!(
+            Ok(Vec::from([
+                AltSvcEntry {
+                    protocol_id: "h2",
+                    authority: ":443",
+                    max_age: Some(3600),
+                    persist: false,
+                },
+                AltSvcEntry {
+                    protocol_id: "h3",
+                    authority: "alt.example.com:443",
+                    max_age: None,
+                    persist: true,
+                },
+            ])),
+            alt_svc.alternatives()
+        );
+
+        let bytes = frame_bytes(FrameType::ALTSVC, Flags::NONE, 1, b"\x00\x00clear");
+        let (_, Frame::AltSvc(_, clear)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected an ALTSVC frame");
+        };
+        assert!(clear.origin.is_empty());
+        assert_eq!(Ok(Vec::new()), clear.alternatives());
+
+        let bytes = frame_bytes(FrameType::ALTSVC, Flags::NONE, 1, b"\x00\x00h2=\":443");
+        let (_, Frame::AltSvc(_, unterminated)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected an ALTSVC frame");
+        };
+        assert!(matches!(unterminated.alternatives(), Err(Http2ParseError::Malformed(_))));
+    }
+
+    #[cfg(feature = "rfc7838")]
+    #[test]
+    fn test_alt_svc_on_request_stream() {
+        use crate::test_corpus::altsvc_frame_bytes;
+
+        let bytes = altsvc_frame_bytes(3, b"", b"h2=\"alt.example.com:443\"");
+        let (tail, frame) = Frame::parse(&bytes).unwrap();
+        assert!(tail.is_empty());
+        let Frame::AltSvc(_, alt_svc) = frame else {
+            panic!("expected an ALTSVC frame");
+        };
+        assert!(!alt_svc.has_explicit_origin());
+        assert_eq!(b"h2=\"alt.example.com:443\"", alt_svc.field_value);
+        assert_eq!(1, alt_svc.alternatives().unwrap().len());
+
+        let mut buf = Vec::new();
+        frame.write(&mut buf).unwrap();
+        assert_eq!(bytes, buf);
+
+        let bytes = altsvc_frame_bytes(0, b"https://example.com", b"clear");
+        let (_, Frame::AltSvc(_, alt_svc)) = Frame::parse(&bytes).unwrap() else {
+            panic!("expected an ALTSVC frame");
+        };
+        assert!(alt_svc.has_explicit_origin());
     }
 }
@@ -0,0 +1,220 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::cmp::Reverse;
+
+use crate::frames::{Frame, StreamDependency};
+
+/// The weight field assigned to streams without explicit priority; an effective weight of 16
+/// (RFC 7540 §5.3.5).
+pub const DEFAULT_WEIGHT: u8 = 15;
+
+#[derive(Debug, Clone)]
+struct Node {
+    parent: u32,
+    weight: u8,
+    children: Vec<u32>,
+}
+
+impl Node {
+    fn new(parent: u32, weight: u8) -> Self {
+        Self {
+            parent,
+            weight,
+            children: Vec::new(),
+        }
+    }
+}
+
+/// The RFC 7540 §5.3 stream dependency tree, rooted at stream 0.
+///
+/// Weights are stored as they appear on the wire, i.e. one less than the effective weight.
+#[derive(Debug, Clone)]
+pub struct PriorityTree {
+    nodes: BTreeMap<u32, Node>,
+}
+
+impl Default for PriorityTree {
+    fn default() -> Self {
+        let mut nodes = BTreeMap::new();
+        nodes.insert(0, Node::new(0, DEFAULT_WEIGHT));
+        Self { nodes }
+    }
+}
+
+impl PriorityTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applies the priority fields of a PRIORITY frame, or of a HEADERS frame carrying them.
+    pub fn on_frame(&mut self, frame: &Frame) {
+        let stream_id = frame.header().stream_identifier.stream_identifier();
+        match frame {
+            Frame::Priority(_, priority) => {
+                self.on_priority(stream_id, &priority.stream_dependency, priority.weight);
+            }
+            Frame::Headers(_, headers) => {
+                if let Some(dependency) = headers.stream_dependency {
+                    let weight = headers.weight.unwrap_or(DEFAULT_WEIGHT);
+                    self.on_priority(stream_id, &dependency, weight);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Makes `stream_id` depend on `dep` with the given wire `weight` (§5.3.3).
+    ///
+    /// An exclusive dependency adopts the new parent's existing children. If the new parent
+    /// is currently a descendant of `stream_id`, it is first moved up to take the place of
+    /// `stream_id`. A dependency on a stream not in the tree gives that stream the default
+    /// priority. Self-dependencies are ignored; they are a stream error to be reported by the
+    /// caller.
+    pub fn on_priority(&mut self, stream_id: u32, dep: &StreamDependency, weight: u8) {
+        let parent_id = dep.stream_identifier();
+        if stream_id == 0 || parent_id == stream_id {
+            return;
+        }
+
+        if !self.nodes.contains_key(&parent_id) {
+            self.insert(parent_id, 0, DEFAULT_WEIGHT);
+        }
+        if !self.nodes.contains_key(&stream_id) {
+            self.insert(stream_id, 0, DEFAULT_WEIGHT);
+        }
+
+        if self.is_descendant(parent_id, stream_id) {
+            let former_parent = self.nodes[&stream_id].parent;
+            self.detach(parent_id);
+            self.attach(parent_id, former_parent);
+        }
+
+        self.detach(stream_id);
+        if dep.exclusive() {
+            let adopted = core::mem::take(&mut self.node_mut(parent_id).children);
+            for &child in &adopted {
+                self.node_mut(child).parent = stream_id;
+            }
+            self.node_mut(stream_id).children.extend(adopted);
+        }
+        self.attach(stream_id, parent_id);
+        self.node_mut(stream_id).weight = weight;
+    }
+
+    /// Returns the stream a stream depends on, or `None` if it is not in the tree.
+    pub fn parent(&self, stream_id: u32) -> Option<u32> {
+        self.nodes.get(&stream_id).map(|node| node.parent)
+    }
+
+    /// Returns the streams depending directly on a stream, in the order they were added.
+    pub fn children(&self, stream_id: u32) -> &[u32] {
+        self.nodes
+            .get(&stream_id)
+            .map_or(&[], |node| node.children.as_slice())
+    }
+
+    /// Returns the wire weight of a stream, or `None` if it is not in the tree.
+    pub fn weight(&self, stream_id: u32) -> Option<u8> {
+        self.nodes.get(&stream_id).map(|node| node.weight)
+    }
+
+    /// Flattens the tree into a scheduling order: parents before their dependents, and
+    /// siblings by descending weight, then ascending stream id. Stream 0 is omitted.
+    pub fn scheduling_order(&self) -> Vec<u32> {
+        let mut order = Vec::with_capacity(self.nodes.len() - 1);
+        let mut level = Vec::from([0]);
+
+        while !level.is_empty() {
+            let mut next = Vec::new();
+            for stream_id in level {
+                let mut children = self.nodes[&stream_id].children.clone();
+                children.sort_by_key(|&child| (Reverse(self.nodes[&child].weight), child));
+                order.extend_from_slice(&children);
+                next.extend(children);
+            }
+            level = next;
+        }
+
+        order
+    }
+
+    fn node_mut(&mut self, stream_id: u32) -> &mut Node {
+        self.nodes
+            .get_mut(&stream_id)
+            .expect("stream is in the priority tree")
+    }
+
+    fn insert(&mut self, stream_id: u32, parent_id: u32, weight: u8) {
+        self.nodes.insert(stream_id, Node::new(parent_id, weight));
+        self.node_mut(parent_id).children.push(stream_id);
+    }
+
+    fn attach(&mut self, stream_id: u32, parent_id: u32) {
+        self.node_mut(stream_id).parent = parent_id;
+        self.node_mut(parent_id).children.push(stream_id);
+    }
+
+    fn detach(&mut self, stream_id: u32) {
+        let parent_id = self.nodes[&stream_id].parent;
+        self.node_mut(parent_id)
+            .children
+            .retain(|&child| child != stream_id);
+    }
+
+    fn is_descendant(&self, stream_id: u32, ancestor: u32) -> bool {
+        let mut current = stream_id;
+        while current != 0 {
+            current = self.nodes[&current].parent;
+            if current == ancestor {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod priority_tests {
+    use alloc::vec::Vec;
+
+    use crate::frames::StreamDependency;
+
+    use super::PriorityTree;
+
+    fn dependency(stream_id: u32, exclusive: bool) -> StreamDependency {
+        StreamDependency::new()
+            .with_stream_identifier(stream_id)
+            .with_exclusive(exclusive)
+    }
+
+    #[test]
+    fn test_exclusive_reparenting() {
+        let mut tree = PriorityTree::new();
+        tree.on_priority(1, &dependency(0, false), 15);
+        tree.on_priority(3, &dependency(1, false), 15);
+        tree.on_priority(5, &dependency(1, false), 31);
+
+        tree.on_priority(7, &dependency(1, true), 63);
+
+        assert_eq!([7], tree.children(1));
+        assert_eq!([3, 5], tree.children(7));
+        assert_eq!(Some(7), tree.parent(3));
+        assert_eq!(Some(7), tree.parent(5));
+        assert_eq!(Some(63), tree.weight(7));
+        assert_eq!(Vec::from([1, 7, 5, 3]), tree.scheduling_order());
+    }
+
+    #[test]
+    fn test_dependency_on_descendant() {
+        let mut tree = PriorityTree::new();
+        tree.on_priority(1, &dependency(0, false), 15);
+        tree.on_priority(3, &dependency(1, false), 15);
+        tree.on_priority(5, &dependency(3, false), 15);
+
+        tree.on_priority(1, &dependency(5, false), 15);
+
+        assert_eq!(Some(0), tree.parent(5));
+        assert_eq!(Some(5), tree.parent(1));
+        assert_eq!(Some(1), tree.parent(3));
+        assert_eq!(Vec::from([5, 1, 3]), tree.scheduling_order());
+    }
+}
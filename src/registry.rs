@@ -0,0 +1,128 @@
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::any::Any;
+
+use crate::{
+    error::Http2ParseError,
+    frames::{Frame, FrameHeader},
+};
+
+/// A decoded extension frame; callers downcast it to the type their parser produced.
+pub type ExtFrame = Box<dyn Any>;
+
+/// Decodes the payload of one extension frame type.
+pub type ExtFrameParser = Box<dyn Fn(&[u8], &FrameHeader) -> Result<ExtFrame, Http2ParseError>>;
+
+/// Parsers for extension frame types registered at runtime, keyed by frame type byte.
+///
+/// Only types this crate does not recognize are dispatched; the known types always use the
+/// built-in parsers.
+#[derive(Default)]
+pub struct FrameRegistry {
+    parsers: BTreeMap<u8, ExtFrameParser>,
+}
+
+impl core::fmt::Debug for FrameRegistry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.parsers.keys()).finish()
+    }
+}
+
+impl FrameRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `parser` for `frame_type`, replacing any parser already registered for it.
+    pub fn register<F>(&mut self, frame_type: u8, parser: F)
+    where
+        F: Fn(&[u8], &FrameHeader) -> Result<ExtFrame, Http2ParseError> + 'static,
+    {
+        self.parsers.insert(frame_type, Box::new(parser));
+    }
+
+    pub fn contains(&self, frame_type: u8) -> bool {
+        self.parsers.contains_key(&frame_type)
+    }
+}
+
+/// A frame parsed by [`Frame::parse_with_registry`].
+#[derive(Debug)]
+pub enum RegisteredFrame<'a> {
+    /// A known frame type, or an unknown one with no registered parser.
+    Frame(Frame<'a>),
+    /// A frame decoded by a registered extension parser.
+    Extension(FrameHeader, ExtFrame),
+}
+
+impl<'a> Frame<'a> {
+    /// Parses one frame, dispatching unrecognized frame types to the parsers in `registry` and
+    /// falling back to [`Frame::Unknown`] for types with no registered parser.
+    pub fn parse_with_registry(
+        bytes: &'a [u8],
+        registry: &FrameRegistry,
+    ) -> Result<(&'a [u8], RegisteredFrame<'a>), Http2ParseError> {
+        let (tail, frame) = Frame::parse(bytes)?;
+
+        let Frame::Unknown(header, payload) = frame else {
+            return Ok((tail, RegisteredFrame::Frame(frame)));
+        };
+        match registry.parsers.get(&u8::from(&header.frame_type)) {
+            Some(parser) => Ok((tail, RegisteredFrame::Extension(header, parser(payload, &header)?))),
+            None => Ok((tail, RegisteredFrame::Frame(frame))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use alloc::boxed::Box;
+
+    use crate::{
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{Frame, FrameType},
+        test_corpus::frame_bytes,
+    };
+
+    use super::{FrameRegistry, RegisteredFrame};
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Heartbeat {
+        sequence: u16,
+    }
+
+    #[test]
+    fn test_registered_extension_frame() {
+        let mut registry = FrameRegistry::new();
+        registry.register(0x20, |payload, _header| {
+            let sequence = payload
+                .try_into()
+                .map(u16::from_be_bytes)
+                .map_err(|_| Http2ParseError::FrameSize)?;
+            Ok(Box::new(Heartbeat { sequence }))
+        });
+        assert!(registry.contains(0x20));
+
+        let bytes = frame_bytes(FrameType::UNKNOWN(0x20), Flags::NONE, 0, &[0x01, 0x02]);
+        let (tail, frame) = Frame::parse_with_registry(&bytes, &registry).unwrap();
+        assert!(tail.is_empty());
+        let RegisteredFrame::Extension(header, ext) = frame else {
+            panic!("expected an extension frame");
+        };
+        assert_eq!(FrameType::UNKNOWN(0x20), header.frame_type);
+        assert_eq!(
+            Some(&Heartbeat { sequence: 0x0102 }),
+            ext.downcast_ref::<Heartbeat>()
+        );
+
+        let bytes = frame_bytes(FrameType::UNKNOWN(0x20), Flags::NONE, 0, &[0x01]);
+        assert_eq!(
+            Some(Http2ParseError::FrameSize),
+            Frame::parse_with_registry(&bytes, &registry).err()
+        );
+
+        let bytes = frame_bytes(FrameType::UNKNOWN(0x21), Flags::NONE, 0, b"xy");
+        let (_, frame) = Frame::parse_with_registry(&bytes, &registry).unwrap();
+        assert!(matches!(frame, RegisteredFrame::Frame(Frame::Unknown(..))));
+    }
+}
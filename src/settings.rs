@@ -0,0 +1,307 @@
+use alloc::vec::Vec;
+
+use crate::{
+    error::Http2ParseError,
+    flags::Flags,
+    frames::{ErrorCode, FrameHeader, FrameType, SettingsParameter, SettingsParameterFrame},
+    owned::{OwnedFrame, OwnedSettingsFrame},
+};
+
+/// The smallest permitted `SETTINGS_MAX_FRAME_SIZE`, which is also its initial value.
+pub const MIN_MAX_FRAME_SIZE: u32 = 16_384;
+
+/// The largest permitted `SETTINGS_MAX_FRAME_SIZE` (2^24 - 1).
+pub const MAX_MAX_FRAME_SIZE: u32 = 16_777_215;
+
+macro_rules! settings_value {
+    ($(#[$meta:meta])* $name:ident($inner:ty)) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($inner);
+
+        impl $name {
+            pub fn get(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl From<$name> for u32 {
+            fn from(value: $name) -> Self {
+                u32::from(value.0)
+            }
+        }
+    };
+}
+
+settings_value!(
+    /// `SETTINGS_HEADER_TABLE_SIZE`: the HPACK dynamic table size in octets. Any value is valid.
+    HeaderTableSize(u32)
+);
+settings_value!(
+    /// `SETTINGS_ENABLE_PUSH`, sent as 0 or 1.
+    EnablePush(bool)
+);
+settings_value!(
+    /// `SETTINGS_MAX_CONCURRENT_STREAMS`. Any value is valid, including 0.
+    MaxConcurrentStreams(u32)
+);
+settings_value!(
+    /// `SETTINGS_INITIAL_WINDOW_SIZE`, at most 2^31-1.
+    InitialWindowSize(u32)
+);
+settings_value!(
+    /// `SETTINGS_MAX_FRAME_SIZE`, from [`MIN_MAX_FRAME_SIZE`] to [`MAX_MAX_FRAME_SIZE`].
+    MaxFrameSize(u32)
+);
+settings_value!(
+    /// `SETTINGS_MAX_HEADER_LIST_SIZE` in octets. Any value is valid.
+    MaxHeaderListSize(u32)
+);
+
+impl From<u32> for HeaderTableSize {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u32> for MaxConcurrentStreams {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<u32> for MaxHeaderListSize {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<bool> for EnablePush {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+/// Fails with a connection `PROTOCOL_ERROR` for anything but 0 or 1 (§6.5.2).
+impl TryFrom<u32> for EnablePush {
+    type Error = Http2ParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self(false)),
+            1 => Ok(Self(true)),
+            _ => Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+        }
+    }
+}
+
+/// Fails with a connection `FLOW_CONTROL_ERROR` above 2^31-1 (§6.5.2).
+impl TryFrom<u32> for InitialWindowSize {
+    type Error = Http2ParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > 0x7FFF_FFFF {
+            return Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Fails with a connection `PROTOCOL_ERROR` outside 2^14 to 2^24-1 (§6.5.2).
+impl TryFrom<u32> for MaxFrameSize {
+    type Error = Http2ParseError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if !(MIN_MAX_FRAME_SIZE..=MAX_MAX_FRAME_SIZE).contains(&value) {
+            return Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// The SETTINGS values in effect for one endpoint, starting from the RFC 7540 §6.5.2 defaults.
+///
+/// Every field is fixed-size, so the struct is `Copy` and applying a frame to it never allocates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Http2Settings {
+    pub header_table_size: HeaderTableSize,
+    pub enable_push: EnablePush,
+    /// `None` means no limit has been advertised.
+    pub max_concurrent_streams: Option<MaxConcurrentStreams>,
+    pub initial_window_size: InitialWindowSize,
+    pub max_frame_size: MaxFrameSize,
+    /// `None` means no limit has been advertised.
+    pub max_header_list_size: Option<MaxHeaderListSize>,
+}
+
+impl Default for Http2Settings {
+    fn default() -> Self {
+        Self {
+            header_table_size: HeaderTableSize(4_096),
+            enable_push: EnablePush(true),
+            max_concurrent_streams: None,
+            initial_window_size: InitialWindowSize(65_535),
+            max_frame_size: MaxFrameSize(MIN_MAX_FRAME_SIZE),
+            max_header_list_size: None,
+        }
+    }
+}
+
+impl Http2Settings {
+    /// Validates and applies a single parameter; reserved parameters are ignored (§6.5.2).
+    pub fn apply(
+        &mut self,
+        parameter: SettingsParameter,
+        value: u32,
+    ) -> Result<(), Http2ParseError> {
+        match parameter {
+            SettingsParameter::SETTINGS_HEADER_TABLE_SIZE => {
+                self.header_table_size = value.into();
+            }
+            SettingsParameter::SETTINGS_ENABLE_PUSH => self.enable_push = value.try_into()?,
+            SettingsParameter::SETTINGS_MAX_CONCURRENT_STREAMS => {
+                self.max_concurrent_streams = Some(value.into());
+            }
+            SettingsParameter::SETTINGS_INITIAL_WINDOW_SIZE => {
+                self.initial_window_size = value.try_into()?;
+            }
+            SettingsParameter::SETTINGS_MAX_FRAME_SIZE => self.max_frame_size = value.try_into()?,
+            SettingsParameter::SETTINGS_MAX_HEADER_LIST_SIZE => {
+                self.max_header_list_size = Some(value.into());
+            }
+            SettingsParameter::RESERVED(_) => {}
+        }
+
+        Ok(())
+    }
+
+    /// Returns the parameters whose values in `other` differ from `self`, in identifier order,
+    /// carrying the values from `other`.
+    ///
+    /// An optional limit that `other` leaves unset cannot be expressed as a parameter, so it
+    /// is omitted.
+    pub fn diff(&self, other: &Http2Settings) -> Vec<(SettingsParameter, u32)> {
+        let fields = [
+            (
+                SettingsParameter::SETTINGS_HEADER_TABLE_SIZE,
+                Some(self.header_table_size.into()),
+                Some(other.header_table_size.into()),
+            ),
+            (
+                SettingsParameter::SETTINGS_ENABLE_PUSH,
+                Some(self.enable_push.into()),
+                Some(other.enable_push.into()),
+            ),
+            (
+                SettingsParameter::SETTINGS_MAX_CONCURRENT_STREAMS,
+                self.max_concurrent_streams.map(u32::from),
+                other.max_concurrent_streams.map(u32::from),
+            ),
+            (
+                SettingsParameter::SETTINGS_INITIAL_WINDOW_SIZE,
+                Some(self.initial_window_size.into()),
+                Some(other.initial_window_size.into()),
+            ),
+            (
+                SettingsParameter::SETTINGS_MAX_FRAME_SIZE,
+                Some(self.max_frame_size.into()),
+                Some(other.max_frame_size.into()),
+            ),
+            (
+                SettingsParameter::SETTINGS_MAX_HEADER_LIST_SIZE,
+                self.max_header_list_size.map(u32::from),
+                other.max_header_list_size.map(u32::from),
+            ),
+        ];
+
+        fields
+            .into_iter()
+            .filter(|(_, current, target)| current != target)
+            .filter_map(|(parameter, _, target)| Some((parameter, target?)))
+            .collect()
+    }
+
+    /// Builds the SETTINGS frame announcing these settings, carrying only the parameters that
+    /// differ from the defaults.
+    pub fn to_frame(&self) -> OwnedFrame {
+        let parameters: Vec<_> = Http2Settings::default()
+            .diff(self)
+            .into_iter()
+            .map(|(parameter, value)| SettingsParameterFrame::new(parameter, value))
+            .collect();
+        let length = u32::try_from(parameters.len() * 6).unwrap_or(u32::MAX);
+        let header = FrameHeader::new(FrameType::SETTINGS, Flags::NONE, 0, length)
+            .expect("at most six parameters fit the frame length");
+        OwnedFrame::Settings(
+            header,
+            OwnedSettingsFrame {
+                parameters: Some(parameters),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod settings_tests {
+    use crate::{
+        error::Http2ParseError,
+        frames::{ErrorCode, SettingsParameter},
+    };
+
+    use super::{Http2Settings, InitialWindowSize, MaxFrameSize};
+
+    #[test]
+    fn test_typed_values() {
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::FLOW_CONTROL_ERROR)),
+            InitialWindowSize::try_from(0x8000_0000)
+        );
+        assert_eq!(Ok(0x7FFF_FFFF), InitialWindowSize::try_from(0x7FFF_FFFF).map(u32::from));
+        assert_eq!(Ok(16_384), MaxFrameSize::try_from(16_384).map(MaxFrameSize::get));
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            MaxFrameSize::try_from(16_383)
+        );
+    }
+
+    #[test]
+    fn test_diff_max_frame_size() {
+        let acknowledged = Http2Settings::default();
+        let pending = Http2Settings {
+            max_frame_size: MaxFrameSize::try_from(32_768).unwrap(),
+            ..acknowledged
+        };
+
+        assert_eq!(
+            [(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 32_768)],
+            acknowledged.diff(&pending).as_slice()
+        );
+        assert!(pending.diff(&pending).is_empty());
+    }
+
+    #[test]
+    fn test_max_frame_size_boundaries() {
+        let mut settings = Http2Settings::default();
+        let protocol_error = Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR));
+
+        assert_eq!(
+            protocol_error,
+            settings.apply(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16_383)
+        );
+        assert_eq!(
+            Ok(()),
+            settings.apply(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16_384)
+        );
+        assert_eq!(16_384, settings.max_frame_size.get());
+        assert_eq!(
+            Ok(()),
+            settings.apply(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16_777_215)
+        );
+        assert_eq!(16_777_215, settings.max_frame_size.get());
+        assert_eq!(
+            protocol_error,
+            settings.apply(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16_777_216)
+        );
+        assert_eq!(16_777_215, settings.max_frame_size.get());
+    }
+}
@@ -0,0 +1,62 @@
+use alloc::collections::BTreeMap;
+
+use crate::frames::{Frame, FrameType};
+
+/// Accumulates per-type frame counts and wire byte totals for monitoring.
+#[derive(Debug, Default)]
+pub struct FrameStats {
+    counts: BTreeMap<FrameType, usize>,
+    bytes: BTreeMap<FrameType, usize>,
+}
+
+impl FrameStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one frame, counting it and adding its full wire length to its type's total.
+    pub fn record(&mut self, frame: &Frame) {
+        let frame_type = frame.summary().frame_type;
+        *self.counts.entry(frame_type).or_default() += 1;
+        *self.bytes.entry(frame_type).or_default() += frame.wire_len();
+    }
+
+    /// Number of frames recorded per frame type.
+    pub fn counts(&self) -> &BTreeMap<FrameType, usize> {
+        &self.counts
+    }
+
+    /// Total wire bytes (header and payload) recorded per frame type.
+    pub fn bytes(&self) -> &BTreeMap<FrameType, usize> {
+        &self.bytes
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use crate::{
+        frames::{Frame, FrameType, SettingsParameter},
+        test_corpus::{ping_frame_bytes, settings_frame_bytes},
+    };
+
+    use super::FrameStats;
+
+    #[test]
+    fn test_record_counts_and_bytes() {
+        let mut stats = FrameStats::new();
+        for bytes in [
+            settings_frame_bytes(&[(SettingsParameter::SETTINGS_ENABLE_PUSH, 0)]),
+            ping_frame_bytes(1, false),
+            ping_frame_bytes(1, true),
+        ] {
+            let (_, frame) = Frame::parse(&bytes).unwrap();
+            stats.record(&frame);
+        }
+
+        assert_eq!(Some(&1), stats.counts().get(&FrameType::SETTINGS));
+        assert_eq!(Some(&2), stats.counts().get(&FrameType::PING));
+        assert_eq!(None, stats.counts().get(&FrameType::DATA));
+        assert_eq!(Some(&15), stats.bytes().get(&FrameType::SETTINGS));
+        assert_eq!(Some(&34), stats.bytes().get(&FrameType::PING));
+    }
+}
@@ -0,0 +1,213 @@
+//! Builders for well-formed frame bytes, shared by the unit tests.
+//!
+//! These encode the wire format by hand rather than through the write paths, so parser and
+//! writer tests check against an independent encoding.
+
+use alloc::vec::Vec;
+
+use crate::{
+    flags::Flags,
+    frames::{ErrorCode, FrameType, SettingsParameter},
+};
+
+/// Encodes a 9-byte frame header followed by `payload`.
+pub fn frame_bytes(
+    frame_type: FrameType,
+    flags: Flags,
+    stream_id: u32,
+    payload: &[u8],
+) -> Vec<u8> {
+    let length = u32::try_from(payload.len()).unwrap();
+    let mut bytes = Vec::with_capacity(9 + payload.len());
+    bytes.extend_from_slice(&length.to_be_bytes()[1..]);
+    bytes.push(u8::from(&frame_type));
+    bytes.push(flags.bits());
+    bytes.extend_from_slice(&stream_id.to_be_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// A DATA frame, padded with `pad` zero octets when given.
+pub fn data_frame_bytes(stream_id: u32, data: &[u8], pad: Option<u8>) -> Vec<u8> {
+    let mut payload = Vec::new();
+    let mut flags = Flags::NONE;
+    if let Some(pad) = pad {
+        flags |= Flags::PADDED;
+        payload.push(pad);
+    }
+    payload.extend_from_slice(data);
+    payload.resize(payload.len() + usize::from(pad.unwrap_or(0)), 0);
+    frame_bytes(FrameType::DATA, flags, stream_id, &payload)
+}
+
+/// A HEADERS frame with END_HEADERS set, carrying `(dependency, weight)` priority fields when
+/// given. Extra `flags` (e.g. END_STREAM) are added to the frame's flags.
+pub fn headers_frame_bytes(
+    stream_id: u32,
+    flags: Flags,
+    fragment: &[u8],
+    priority: Option<(u32, u8)>,
+) -> Vec<u8> {
+    let mut payload = Vec::new();
+    let mut flags = flags | Flags::END_HEADERS;
+    if let Some((dependency, weight)) = priority {
+        flags |= Flags::PRIORITY;
+        payload.extend_from_slice(&dependency.to_be_bytes());
+        payload.push(weight);
+    }
+    payload.extend_from_slice(fragment);
+    frame_bytes(FrameType::HEADERS, flags, stream_id, &payload)
+}
+
+pub fn priority_frame_bytes(stream_id: u32, dependency: u32, weight: u8) -> Vec<u8> {
+    let mut payload = Vec::from(dependency.to_be_bytes());
+    payload.push(weight);
+    frame_bytes(FrameType::PRIORITY, Flags::NONE, stream_id, &payload)
+}
+
+pub fn rst_stream_frame_bytes(stream_id: u32, error_code: ErrorCode) -> Vec<u8> {
+    let payload = u32::from(&error_code).to_be_bytes();
+    frame_bytes(FrameType::RST_STREAM, Flags::NONE, stream_id, &payload)
+}
+
+/// A non-ACK SETTINGS frame on stream 0 carrying `parameters` in order.
+pub fn settings_frame_bytes(parameters: &[(SettingsParameter, u32)]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for (parameter, value) in parameters {
+        payload.extend_from_slice(&u16::from(parameter).to_be_bytes());
+        payload.extend_from_slice(&value.to_be_bytes());
+    }
+    frame_bytes(FrameType::SETTINGS, Flags::NONE, 0, &payload)
+}
+
+pub fn settings_ack_bytes() -> Vec<u8> {
+    frame_bytes(FrameType::SETTINGS, Flags::ACK, 0, &[])
+}
+
+pub fn push_promise_frame_bytes(
+    stream_id: u32,
+    promised_stream_id: u32,
+    fragment: &[u8],
+) -> Vec<u8> {
+    let mut payload = Vec::from(promised_stream_id.to_be_bytes());
+    payload.extend_from_slice(fragment);
+    frame_bytes(FrameType::PUSH_PROMISE, Flags::END_HEADERS, stream_id, &payload)
+}
+
+pub fn ping_frame_bytes(opaque_data: u64, ack: bool) -> Vec<u8> {
+    let flags = if ack { Flags::ACK } else { Flags::NONE };
+    frame_bytes(FrameType::PING, flags, 0, &opaque_data.to_be_bytes())
+}
+
+pub fn goaway_frame_bytes(
+    last_stream_id: u32,
+    error_code: ErrorCode,
+    debug_data: &[u8],
+) -> Vec<u8> {
+    let mut payload = Vec::from(last_stream_id.to_be_bytes());
+    payload.extend_from_slice(&u32::from(&error_code).to_be_bytes());
+    payload.extend_from_slice(debug_data);
+    frame_bytes(FrameType::GOAWAY, Flags::NONE, 0, &payload)
+}
+
+pub fn window_update_frame_bytes(stream_id: u32, increment: u32) -> Vec<u8> {
+    frame_bytes(FrameType::WINDOW_UPDATE, Flags::NONE, stream_id, &increment.to_be_bytes())
+}
+
+pub fn continuation_frame_bytes(stream_id: u32, fragment: &[u8], end_headers: bool) -> Vec<u8> {
+    let flags = if end_headers { Flags::END_HEADERS } else { Flags::NONE };
+    frame_bytes(FrameType::CONTINUATION, flags, stream_id, fragment)
+}
+
+/// An ALTSVC frame for `origin` carrying the Alt-Svc `field_value`.
+pub fn altsvc_frame_bytes(stream_id: u32, origin: &[u8], field_value: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::from(u16::try_from(origin.len()).unwrap().to_be_bytes());
+    payload.extend_from_slice(origin);
+    payload.extend_from_slice(field_value);
+    frame_bytes(FrameType::ALTSVC, Flags::NONE, stream_id, &payload)
+}
+
+/// An ORIGIN frame on stream 0 carrying one entry per origin.
+pub fn origin_frame_bytes(origins: &[&str]) -> Vec<u8> {
+    let mut payload = Vec::new();
+    for origin in origins {
+        payload.extend_from_slice(&u16::try_from(origin.len()).unwrap().to_be_bytes());
+        payload.extend_from_slice(origin.as_bytes());
+    }
+    frame_bytes(FrameType::ORIGIN, Flags::NONE, 0, &payload)
+}
+
+mod corpus_tests {
+    use crate::frames::Frame;
+
+    use super::*;
+
+    #[test]
+    fn test_corpus_frames_parse() {
+        let frames = [
+            data_frame_bytes(1, b"hello", None),
+            headers_frame_bytes(1, Flags::END_STREAM, b"\x82", None),
+            priority_frame_bytes(3, 1, 16),
+            rst_stream_frame_bytes(1, ErrorCode::CANCEL),
+            settings_frame_bytes(&[(SettingsParameter::SETTINGS_MAX_FRAME_SIZE, 16384)]),
+            settings_ack_bytes(),
+            push_promise_frame_bytes(1, 2, b"\x82"),
+            ping_frame_bytes(42, false),
+            goaway_frame_bytes(7, ErrorCode::NO_ERROR, b"bye"),
+            window_update_frame_bytes(0, 1024),
+            continuation_frame_bytes(1, b"\x84", true),
+        ];
+        let types = [
+            FrameType::DATA,
+            FrameType::HEADERS,
+            FrameType::PRIORITY,
+            FrameType::RST_STREAM,
+            FrameType::SETTINGS,
+            FrameType::SETTINGS,
+            FrameType::PUSH_PROMISE,
+            FrameType::PING,
+            FrameType::GOAWAY,
+            FrameType::WINDOW_UPDATE,
+            FrameType::CONTINUATION,
+        ];
+
+        for (bytes, frame_type) in frames.iter().zip(types) {
+            let (tail, frame) = Frame::parse(bytes).unwrap();
+            assert!(tail.is_empty());
+            assert_eq!(frame_type, frame.header().frame_type);
+        }
+
+        let padded = data_frame_bytes(1, b"abc", Some(2));
+        assert_eq!([0x00, 0x00, 0x06, 0x00, 0x08], padded[..5]);
+        assert_eq!([0x02, b'a', b'b', b'c', 0x00, 0x00], padded[9..]);
+
+        let prioritized = headers_frame_bytes(3, Flags::NONE, b"\x82", Some((1, 255)));
+        assert_eq!(Flags::END_HEADERS | Flags::PRIORITY, Flags::from(prioritized[4]));
+        assert_eq!([0x00, 0x00, 0x00, 0x01, 0xFF, 0x82], prioritized[9..]);
+    }
+
+    #[test]
+    fn test_every_frame_type_parses() {
+        let frames = [
+            altsvc_frame_bytes(0, b"https://example.com", b"h2=\":443\""),
+            origin_frame_bytes(&["https://example.com"]),
+            frame_bytes(FrameType::UNKNOWN(0xff), Flags::NONE, 1, b"opaque"),
+        ];
+        for bytes in &frames {
+            let (tail, frame) = Frame::parse(bytes).unwrap();
+            assert!(tail.is_empty());
+            assert_eq!(bytes[3], u8::from(&frame.header().frame_type));
+        }
+
+        // Whatever the type byte, flags, and payload size, parsing returns rather than panics.
+        for type_byte in 0..=u8::MAX {
+            for flags in [Flags::NONE, Flags(0xFF)] {
+                for len in [0, 1, 4, 5, 8, 12] {
+                    let frame_type = FrameType::from(type_byte);
+                    let bytes = frame_bytes(frame_type, flags, 1, &[0x01; 12][..len]);
+                    let _ = Frame::parse(&bytes);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    error::Http2ParseError,
+    flags::Flags,
+    frames::{ErrorCode, Frame},
+    settings::Http2Settings,
+};
+
+impl Frame<'_> {
+    /// Checks an in-memory frame against RFC 7540 before it is sent, without re-parsing.
+    ///
+    /// This covers the stream identifier rules for each type, agreement between the PADDED
+    /// and PRIORITY flags and their fields, the declared length against both the payload and
+    /// `settings.max_frame_size`, and the value ranges of SETTINGS parameters.
+    pub fn validate(&self, settings: &Http2Settings) -> Result<(), Http2ParseError> {
+        let header = self.header();
+        let stream_id = header.stream_identifier.stream_identifier();
+        let flags = header.flags;
+        let connection_error = Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR);
+
+        let length = header.length.length();
+        if length > settings.max_frame_size.get() || length as usize != self.payload_len() {
+            return Err(Http2ParseError::FrameSize);
+        }
+
+        header.validate_stream_identifier()?;
+
+        let padding = match self {
+            Frame::Data(_, frame) => Some((frame.pad_length, frame.padding)),
+            Frame::Headers(_, frame) => Some((frame.pad_length, frame.padding)),
+            Frame::PushPromise(_, frame) => Some((frame.pad_length, frame.padding)),
+            _ => None,
+        };
+        if let Some((pad_length, padding)) = padding {
+            let consistent = match (pad_length, padding) {
+                (Some(pad_length), Some(padding)) => usize::from(pad_length) == padding.len(),
+                (None, None) => true,
+                _ => false,
+            };
+            if !consistent || flags.contains(Flags::PADDED) != pad_length.is_some() {
+                return Err(connection_error);
+            }
+        }
+
+        let stream_dependency = match self {
+            Frame::Priority(_, priority) => Some(&priority.stream_dependency),
+            Frame::Headers(_, headers) => headers.stream_dependency.as_ref(),
+            _ => None,
+        };
+        if stream_dependency.is_some_and(|dependency| dependency.stream_identifier() == stream_id)
+        {
+            return Err(Http2ParseError::Stream {
+                stream_identifier: stream_id,
+                error_code: ErrorCode::PROTOCOL_ERROR,
+            });
+        }
+
+        match self {
+            Frame::Headers(_, frame)
+                if flags.contains(Flags::PRIORITY) != frame.stream_dependency.is_some()
+                    || frame.stream_dependency.is_some() != frame.weight.is_some() =>
+            {
+                Err(connection_error)
+            }
+            Frame::Settings(..) if flags.contains(Flags::ACK) && length != 0 => {
+                Err(Http2ParseError::FrameSize)
+            }
+            Frame::Settings(_, frame) => {
+                let mut applied = *settings;
+                frame
+                    .into_iter()
+                    .try_for_each(|(parameter, value)| applied.apply(parameter, value))
+            }
+            Frame::WindowUpdate(_, frame) if frame.window_size_increment.value() == 0 => {
+                Err(if stream_id == 0 {
+                    connection_error
+                } else {
+                    Http2ParseError::Stream {
+                        stream_identifier: stream_id,
+                        error_code: ErrorCode::PROTOCOL_ERROR,
+                    }
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Header fields that are specific to an HTTP/1.1 connection and must not appear in HTTP/2.
+const CONNECTION_SPECIFIC_HEADERS: [&str; 5] =
+    ["connection", "keep-alive", "proxy-connection", "transfer-encoding", "upgrade"];
+
+/// Checks a decoded header list against the HTTP/2 message rules of RFC 7540 §8.1.2.
+///
+/// HPACK decoding is out of scope here, so the caller passes the fields it decoded in order.
+/// Names must be lowercase; pseudo-headers must come first, be defined for the message kind,
+/// and appear at most once; connection-specific fields are forbidden, and `te` may only carry
+/// `trailers`. A request needs `:method` plus a `:scheme` and non-empty `:path`, or only an
+/// `:authority` for CONNECT (§8.3), while a response needs `:status`.
+///
+/// Any violation makes the message malformed, a stream error on `stream_id` (§8.1.2.6)
+/// reported as `PROTOCOL_ERROR`; the caller is expected to reset that stream.
+pub fn validate_headers(
+    stream_id: u32,
+    headers: &[(&str, &str)],
+    is_request: bool,
+) -> Result<(), Http2ParseError> {
+    let malformed = Err(Http2ParseError::Stream {
+        stream_identifier: stream_id,
+        error_code: ErrorCode::PROTOCOL_ERROR,
+    });
+    let allowed_pseudo: &[&str] = if is_request {
+        &[":method", ":scheme", ":authority", ":path"]
+    } else {
+        &[":status"]
+    };
+
+    let mut seen_regular = false;
+    let mut pseudo_headers: [Option<&str>; 4] = [None; 4];
+    for &(name, value) in headers {
+        if name.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            return malformed;
+        }
+
+        if name.starts_with(':') {
+            let Some(index) = allowed_pseudo.iter().position(|allowed| *allowed == name) else {
+                return malformed;
+            };
+            if seen_regular || pseudo_headers[index].replace(value).is_some() {
+                return malformed;
+            }
+            continue;
+        }
+
+        seen_regular = true;
+        if CONNECTION_SPECIFIC_HEADERS.contains(&name) || (name == "te" && value != "trailers") {
+            return malformed;
+        }
+    }
+
+    let complete = if is_request {
+        let [method, scheme, authority, path] = pseudo_headers;
+        match method {
+            Some("CONNECT") => authority.is_some() && scheme.is_none() && path.is_none(),
+            Some(_) => scheme.is_some() && path.is_some_and(|path| !path.is_empty()),
+            None => false,
+        }
+    } else {
+        pseudo_headers[0].is_some()
+    };
+    if !complete {
+        return malformed;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod validate_tests {
+    use crate::{
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{DataFrame, ErrorCode, Frame, FrameHeader, FrameType},
+        settings::Http2Settings,
+    };
+
+    use super::validate_headers;
+
+    #[test]
+    fn test_validate_headers() {
+        let malformed = Err(Http2ParseError::Stream {
+            stream_identifier: 1,
+            error_code: ErrorCode::PROTOCOL_ERROR,
+        });
+        let request = [
+            (":method", "GET"),
+            (":scheme", "https"),
+            (":path", "/"),
+            ("accept", "*/*"),
+        ];
+        assert_eq!(Ok(()), validate_headers(1, &request, true));
+        assert_eq!(malformed, validate_headers(1, &request, false));
+        assert_eq!(Ok(()), validate_headers(1, &[(":status", "200"), ("te", "trailers")], false));
+
+        let pseudo_after_regular = [(":method", "GET"), ("accept", "*/*"), (":path", "/")];
+        assert_eq!(malformed, validate_headers(1, &pseudo_after_regular, true));
+
+        let connection = [(":status", "200"), ("connection", "keep-alive")];
+        assert_eq!(malformed, validate_headers(1, &connection, false));
+
+        let empty_path = [(":method", "GET"), (":scheme", "https"), (":path", "")];
+        assert_eq!(malformed, validate_headers(1, &empty_path, true));
+
+        let connect = [(":method", "CONNECT"), (":authority", "example.com:443")];
+        assert_eq!(Ok(()), validate_headers(1, &connect, true));
+    }
+
+    fn data_frame(stream_id: u32, flags: Flags, length: u32, frame: DataFrame) -> Frame {
+        Frame::Data(
+            FrameHeader::new(FrameType::DATA, flags, stream_id, length).unwrap(),
+            frame,
+        )
+    }
+
+    #[test]
+    fn test_validate_data_frame() {
+        let settings = Http2Settings::default();
+        let data = DataFrame {
+            pad_length: None,
+            data: b"hello",
+            padding: None,
+        };
+
+        assert_eq!(Ok(()), data_frame(1, Flags::NONE, 5, data).validate(&settings));
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            data_frame(0, Flags::NONE, 5, data).validate(&settings)
+        );
+        assert_eq!(
+            Err(Http2ParseError::FrameSize),
+            data_frame(1, Flags::NONE, 4, data).validate(&settings)
+        );
+        assert_eq!(
+            Err(Http2ParseError::Connection(ErrorCode::PROTOCOL_ERROR)),
+            data_frame(1, Flags::PADDED, 5, data).validate(&settings)
+        );
+
+        let padded = DataFrame {
+            pad_length: Some(2),
+            data: b"hello",
+            padding: Some(&[0; 2]),
+        };
+        assert_eq!(Ok(()), data_frame(1, Flags::PADDED, 8, padded).validate(&settings));
+
+        let oversized = DataFrame {
+            pad_length: None,
+            data: &[0; 16_385],
+            padding: None,
+        };
+        assert_eq!(
+            Err(Http2ParseError::FrameSize),
+            data_frame(1, Flags::NONE, 16_385, oversized).validate(&settings)
+        );
+    }
+}
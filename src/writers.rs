@@ -0,0 +1,276 @@
+use alloc::vec::Vec;
+
+use bytes::BufMut;
+use zerocopy::IntoBytes;
+
+use crate::{
+    connection::CONNECTION_PREFACE,
+    error::Http2ParseError,
+    flags::Flags,
+    frames::{
+        ErrorCode, Frame, FrameHeader, GoAwayFrame, OriginEntry, StreamIdentifier,
+    },
+    owned::OwnedFrame,
+    settings::Http2Settings,
+};
+
+/// Returns the bytes a client opens a connection with: the connection preface followed by a
+/// SETTINGS frame announcing `settings`.
+pub fn client_handshake(settings: &Http2Settings) -> Vec<u8> {
+    let mut handshake = Vec::from(*CONNECTION_PREFACE);
+    settings
+        .to_frame()
+        .write(&mut handshake)
+        .expect("a SETTINGS frame has no fields that can overflow");
+    handshake
+}
+
+pub fn write_stream_identifier<B: BufMut>(dst: &mut B, stream_identifier: &StreamIdentifier) {
+    dst.put_u32(stream_identifier.stream_identifier());
+}
+
+pub fn write_optional_padding_length<B: BufMut>(dst: &mut B, pad_length: Option<u8>) {
+    if let Some(pad_length) = pad_length {
+        dst.put_u8(pad_length);
+    }
+}
+
+pub fn write_optional_padding_bytes<B: BufMut>(dst: &mut B, padding: Option<&[u8]>) {
+    if let Some(padding) = padding {
+        dst.put_slice(padding);
+    }
+}
+
+pub fn write_error_code<B: BufMut>(dst: &mut B, error_code: &ErrorCode) {
+    dst.put_u32(u32::from(error_code));
+}
+
+impl FrameHeader {
+    pub fn write<B: BufMut>(&self, dst: &mut B) {
+        dst.put_uint(u64::from(self.length.length()), 3);
+        dst.put_u8(u8::from(&self.frame_type));
+        dst.put_u8(self.flags.bits());
+        write_stream_identifier(dst, &self.stream_identifier);
+    }
+}
+
+impl GoAwayFrame<'_> {
+    /// Writes the frame with its header. Debug data too long for one frame fails with
+    /// [`Http2ParseError::FrameSize`] before anything is written.
+    pub fn write<B: BufMut>(&self, dst: &mut B) -> Result<(), Http2ParseError> {
+        self.frame_header()?.write(dst);
+        write_stream_identifier(dst, &self.last_stream_identifier);
+        write_error_code(dst, &self.error_code);
+        dst.put_slice(self.debug_data.unwrap_or_default());
+        Ok(())
+    }
+}
+
+impl OriginEntry<'_> {
+    /// Writes the 2-octet length followed by the ASCII origin.
+    pub fn write<B: BufMut>(&self, dst: &mut B) {
+        dst.put_u16(self.origin_length);
+        dst.put_slice(self.ascii_origin.unwrap_or_default().as_bytes());
+    }
+}
+
+impl Frame<'_> {
+    /// Writes the header exactly as stored, followed by the payload fields of this frame.
+    ///
+    /// An ALTSVC origin too long for its 2-octet length fails with
+    /// [`Http2ParseError::FrameSize`] before anything is written.
+    pub fn write<B: BufMut>(&self, dst: &mut B) -> Result<(), Http2ParseError> {
+        let origin_len = match self {
+            Frame::AltSvc(_, frame) => {
+                u16::try_from(frame.origin.len()).map_err(|_| Http2ParseError::FrameSize)?
+            }
+            _ => 0,
+        };
+        self.header().write(dst);
+
+        match self {
+            Frame::Data(_, frame) => {
+                write_optional_padding_length(dst, frame.pad_length);
+                dst.put_slice(frame.data);
+                write_optional_padding_bytes(dst, frame.padding);
+            }
+            Frame::Headers(_, frame) => {
+                write_optional_padding_length(dst, frame.pad_length);
+                if let Some(stream_dependency) = frame.stream_dependency {
+                    dst.put_u32(stream_dependency.into_bits());
+                }
+                if let Some(weight) = frame.weight {
+                    dst.put_u8(weight);
+                }
+                dst.put_slice(frame.header_block_fragment);
+                write_optional_padding_bytes(dst, frame.padding);
+            }
+            Frame::Priority(_, frame) => {
+                dst.put_u32(frame.stream_dependency.into_bits());
+                dst.put_u8(frame.weight);
+            }
+            Frame::RstStream(_, frame) => write_error_code(dst, &frame.error_code),
+            Frame::Settings(header, frame) => {
+                debug_assert!(
+                    !header.flags.contains(Flags::ACK)
+                        || (frame.parameters.is_none() && header.length.length() == 0),
+                    "a SETTINGS ACK must not carry parameters"
+                );
+                dst.put_slice(frame.parameters.unwrap_or_default().as_bytes());
+            }
+            Frame::PushPromise(_, frame) => {
+                write_optional_padding_length(dst, frame.pad_length);
+                write_stream_identifier(dst, &frame.promised_stream_identifier);
+                dst.put_slice(frame.header_block_fragment);
+                write_optional_padding_bytes(dst, frame.padding);
+            }
+            Frame::Ping(_, frame) => dst.put_u64(frame.opaque_data),
+            Frame::GoAway(_, frame) => {
+                write_stream_identifier(dst, &frame.last_stream_identifier);
+                write_error_code(dst, &frame.error_code);
+                dst.put_slice(frame.debug_data.unwrap_or_default());
+            }
+            Frame::WindowUpdate(_, frame) => {
+                dst.put_u32(frame.window_size_increment.window_size());
+            }
+            Frame::Continuation(_, frame) => dst.put_slice(frame.header_block_fragment),
+            Frame::AltSvc(_, frame) => {
+                dst.put_u16(origin_len);
+                dst.put_slice(frame.origin);
+                dst.put_slice(frame.field_value);
+            }
+            Frame::Origin(_, frame) => dst.put_slice(frame.payload),
+            Frame::Ignored(_, payload) | Frame::Unknown(_, payload) => dst.put_slice(payload),
+        }
+        Ok(())
+    }
+}
+
+impl OwnedFrame {
+    pub fn write<B: BufMut>(&self, dst: &mut B) -> Result<(), Http2ParseError> {
+        self.as_ref().write(dst)
+    }
+}
+
+#[cfg(test)]
+mod write_tests {
+    use alloc::{vec, vec::Vec};
+
+    use crate::{
+        connection::CONNECTION_PREFACE,
+        error::Http2ParseError,
+        flags::Flags,
+        frames::{
+            ErrorCode, Frame, FrameHeader, FrameType, GoAwayFrame, SettingsParameter,
+            WindowUpdateFrame,
+        },
+        owned::OwnedSettingsFrame,
+        settings::Http2Settings,
+    };
+
+    use super::client_handshake;
+
+    #[test]
+    fn test_settings_ack_encoding() {
+        let with_parameters: OwnedSettingsFrame =
+            [(SettingsParameter::SETTINGS_ENABLE_PUSH, 0)].into_iter().collect();
+        assert_eq!(Err(Http2ParseError::FrameSize), with_parameters.into_ack_frame());
+
+        let ack = OwnedSettingsFrame { parameters: None }.into_ack_frame().unwrap();
+        let mut buf = Vec::new();
+        ack.write(&mut buf).unwrap();
+        assert_eq!([0x00, 0x00, 0x00, 0x04, 0x01, 0x00, 0x00, 0x00, 0x00], buf[..]);
+    }
+
+    #[test]
+    fn test_client_handshake() {
+        let settings = Http2Settings {
+            enable_push: false.into(),
+            max_concurrent_streams: Some(100.into()),
+            ..Http2Settings::default()
+        };
+        let handshake = client_handshake(&settings);
+        assert!(handshake.starts_with(CONNECTION_PREFACE));
+
+        let (tail, frame) = Frame::parse(&handshake[CONNECTION_PREFACE.len()..]).unwrap();
+        let Frame::Settings(header, parsed) = frame else {
+            panic!("expected a SETTINGS frame");
+        };
+        assert!(tail.is_empty());
+        assert_eq!(Flags::NONE, header.flags);
+        assert_eq!(
+            [
+                (SettingsParameter::SETTINGS_ENABLE_PUSH, 0),
+                (SettingsParameter::SETTINGS_MAX_CONCURRENT_STREAMS, 100),
+            ],
+            parsed.into_iter().collect::<Vec<_>>()[..]
+        );
+    }
+
+    #[test]
+    fn test_goaway_round_trip() {
+        let frame = GoAwayFrame::new(0x8000_0005, ErrorCode::PROTOCOL_ERROR, Some(b"bad hpack"));
+        let mut buf = Vec::new();
+        frame.write(&mut buf).unwrap();
+
+        let (bytes, header) = FrameHeader::parse(&buf).unwrap();
+        assert_eq!(17, header.length.length());
+        assert_eq!(FrameType::GOAWAY, header.frame_type);
+        assert_eq!(0, header.stream_identifier.stream_identifier());
+
+        let (tail, parsed) = GoAwayFrame::parse(bytes, &header.length).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(5, parsed.last_stream_identifier.stream_identifier());
+        assert_eq!(0, parsed.last_stream_identifier.into_bits() >> 31);
+        assert_eq!(ErrorCode::PROTOCOL_ERROR, parsed.error_code);
+        assert_eq!(Some(&b"bad hpack"[..]), parsed.debug_data);
+    }
+
+    #[test]
+    fn test_goaway_oversized_debug_data() {
+        let debug = vec![0; 0xFF_FFFF - 8];
+        let mut buf = Vec::new();
+        GoAwayFrame::new(1, ErrorCode::NO_ERROR, Some(&debug)).write(&mut buf).unwrap();
+        assert_eq!(9 + 0xFF_FFFF, buf.len());
+
+        let debug = vec![0; 0xFF_FFFF - 7];
+        let mut buf = Vec::new();
+        let frame = GoAwayFrame::new(1, ErrorCode::NO_ERROR, Some(&debug));
+        assert_eq!(Err(Http2ParseError::FrameSize), frame.write(&mut buf));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_altsvc_origin_too_long() {
+        use crate::frames::AltSvcFrame;
+
+        let origin = vec![b'a'; 0x1_0000];
+        let header = FrameHeader::new(FrameType::ALTSVC, Flags::NONE, 0, 2 + 0x1_0000).unwrap();
+        let frame = Frame::AltSvc(
+            header,
+            AltSvcFrame {
+                origin: &origin,
+                field_value: b"clear",
+            },
+        );
+        let mut buf = Vec::new();
+        assert_eq!(Err(Http2ParseError::FrameSize), frame.write(&mut buf));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_window_update_round_trip() {
+        let frame = WindowUpdateFrame::new(1024).unwrap();
+        let header = FrameHeader::new(FrameType::WINDOW_UPDATE, Flags::NONE, 3, 4).unwrap();
+        let mut buf = Vec::new();
+        Frame::WindowUpdate(header, frame).write(&mut buf).unwrap();
+
+        assert_eq!(
+            [0x00, 0x00, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x04, 0x00],
+            buf[..]
+        );
+        let (tail, parsed) = Frame::parse(&buf).unwrap();
+        assert!(tail.is_empty());
+        assert_eq!(Frame::WindowUpdate(header, frame), parsed);
+    }
+}